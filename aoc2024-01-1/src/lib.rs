@@ -1,6 +1,9 @@
 use std::ops::Sub;
 
-use common::parse::{self};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use nom::{character::complete::space1, sequence::separated_pair};
 use thiserror::Error;
 
@@ -8,21 +11,53 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
-pub fn l2_distance_between_lists(it: impl Iterator<Item = String>) -> Result<u64> {
-    let (mut left, mut right): (Vec<_>, Vec<_>) = it
-        .filter_map(|row| {
-            UnsortedRow::parse(row.as_str())
-                .ok()
-                .map(|UnsortedRow { left, right }| (left, right))
-        })
-        .unzip();
-    left.sort();
-    right.sort();
-    Ok(List(left) - List(right))
+pub fn l2_distance_between_lists(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Lists::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+    type Input = Lists;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Lists> {
+        Ok(Lists::parse(input.lines(), mode)?)
+    }
+
+    fn solve(
+        Lists {
+            mut left,
+            mut right,
+        }: Lists,
+    ) -> Result<u64> {
+        left.sort();
+        right.sort();
+        Ok(List(left) - List(right))
+    }
+}
+
+pub struct Lists {
+    left: Vec<u64>,
+    right: Vec<u64>,
+}
+
+impl Lists {
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>, mode: Mode) -> parse::Result<Self> {
+        let (left, right) = parse::lines(rows, mode, UnsortedRow::parse)?
+            .into_iter()
+            .map(|UnsortedRow { left, right }| (left, right))
+            .unzip();
+        Ok(Self { left, right })
+    }
 }
 
 struct UnsortedRow {
@@ -51,11 +86,11 @@ impl Sub for List {
     }
 }
 
-impl UnsortedRow {
-    fn parse(input: &str) -> parse::Result<Self> {
+impl Parse for UnsortedRow {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (_, (left, right)) = separated_pair(u64, space1, u64)(input)?;
-        Ok(Self { left, right })
+        let (rest, (left, right)) = separated_pair(u64, space1, u64)(input)?;
+        Ok((rest, Self { left, right }))
     }
 }
 
@@ -75,9 +110,25 @@ mod test {
             3   3
         "};
         assert_eq!(
-            l2_distance_between_lists(example.lines().map(String::from))?,
+            l2_distance_between_lists(example.lines().map(String::from), Mode::Strict)?,
             11
         );
         Ok(())
     }
+
+    #[test]
+    fn malformed_line() -> Result<()> {
+        let example = indoc! {"
+            3   4
+            4   x
+            2   5
+        "};
+        let lines = || example.lines().map(String::from);
+        assert!(matches!(
+            l2_distance_between_lists(lines(), Mode::Strict),
+            Err(Error::Parse(parse::Error::Diagnostics(errors))) if errors[0].line == 2 && errors[0].column == 5
+        ));
+        assert_eq!(l2_distance_between_lists(lines(), Mode::Lenient)?, 4);
+        Ok(())
+    }
 }
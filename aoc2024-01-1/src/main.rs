@@ -1,11 +1,9 @@
-use std::io;
+use std::process::ExitCode;
 
-use aoc2024_01_1::{l2_distance_between_lists, Result};
+use aoc2024_01_1::Solver;
+use common::cli;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     tracing_subscriber::fmt::init();
-    let lines = io::stdin().lines().map_while(|l| l.ok());
-    let answer = l2_distance_between_lists(lines)?;
-    println!("Answer: {answer}");
-    Ok(())
+    cli::run::<Solver>()
 }
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use common::parse::{self};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use nom::{character::complete::space1, sequence::separated_pair};
 use thiserror::Error;
 
@@ -8,23 +11,50 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
-pub fn similarity_score(it: impl Iterator<Item = String>) -> Result<u64> {
-    let (left, right): (Vec<_>, Vec<_>) = it
-        .filter_map(|row| {
-            UnsortedRow::parse(row.as_str())
-                .ok()
-                .map(|UnsortedRow { left, right }| (left, right))
-        })
-        .unzip();
-    let mut frequencies = Freq::default();
-    for r in right {
-        frequencies.push(r);
+pub fn similarity_score(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Lists::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Historian Hysteria";
+    type Input = Lists;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Lists> {
+        Ok(Lists::parse(input.lines(), mode)?)
+    }
+
+    fn solve(Lists { left, right }: Lists) -> Result<u64> {
+        let mut frequencies = Freq::default();
+        for r in right {
+            frequencies.push(r);
+        }
+        Ok(left.iter().map(|l| l * frequencies.get_count(*l)).sum())
+    }
+}
+
+pub struct Lists {
+    left: Vec<u64>,
+    right: Vec<u64>,
+}
+
+impl Lists {
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>, mode: Mode) -> parse::Result<Self> {
+        let (left, right) = parse::lines(rows, mode, UnsortedRow::parse)?
+            .into_iter()
+            .map(|UnsortedRow { left, right }| (left, right))
+            .unzip();
+        Ok(Self { left, right })
     }
-    Ok(left.iter().map(|l| l * frequencies.get_count(*l)).sum())
 }
 
 struct UnsortedRow {
@@ -49,21 +79,11 @@ impl Freq {
     }
 }
 
-struct List(Vec<u64>);
-
-impl IntoIterator for List {
-    type Item = u64;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
-
-impl UnsortedRow {
-    fn parse(input: &str) -> parse::Result<Self> {
+impl Parse for UnsortedRow {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (_, (left, right)) = separated_pair(u64, space1, u64)(input)?;
-        Ok(Self { left, right })
+        let (rest, (left, right)) = separated_pair(u64, space1, u64)(input)?;
+        Ok((rest, Self { left, right }))
     }
 }
 
@@ -82,7 +102,10 @@ mod test {
             3   9
             3   3
         "};
-        assert_eq!(similarity_score(example.lines().map(String::from))?, 31);
+        assert_eq!(
+            similarity_score(example.lines().map(String::from), Mode::Strict)?,
+            31
+        );
         Ok(())
     }
 }
@@ -1,11 +1,9 @@
-use std::io;
+use std::process::ExitCode;
 
-use aoc2024_01_2::{similarity_score, Result};
+use aoc2024_01_2::Solver;
+use common::cli;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     tracing_subscriber::fmt::init();
-    let lines = io::stdin().lines().map_while(|l| l.ok());
-    let answer = similarity_score(lines)?;
-    println!("Answer: {answer}");
-    Ok(())
+    cli::run::<Solver>()
 }
@@ -1,4 +1,7 @@
-use common::parse::{self};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use itertools::Itertools;
 use nom::{character::complete::space1, multi::separated_list1};
 use thiserror::Error;
@@ -7,7 +10,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
     #[error("consecutive levels are equal")]
     ConsecutiveEquals,
@@ -15,11 +18,35 @@ pub enum Error {
     BigJump,
 }
 
-pub fn num_safe_reports(it: impl Iterator<Item = String>) -> Result<usize> {
-    Ok(it
-        .filter_map(|ref line| Levels::parse(line).ok())
-        .filter(Levels::safe)
-        .count())
+pub fn num_safe_reports(it: impl Iterator<Item = String>, mode: Mode) -> Result<usize> {
+    Solver::solve(Reports::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Red-Nosed Reports";
+    type Input = Reports;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Reports> {
+        Ok(Reports::parse(input.lines(), mode)?)
+    }
+
+    fn solve(reports: Reports) -> Result<usize> {
+        Ok(reports.0.iter().filter(|levels| levels.safe()).count())
+    }
+}
+
+pub struct Reports(Vec<Levels>);
+
+impl Reports {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> parse::Result<Self> {
+        Ok(Self(parse::lines(lines, mode, Levels::parse)?))
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -57,13 +84,15 @@ fn until_err<T>(err: &mut &mut Result<()>, item: Result<T>) -> Option<T> {
     }
 }
 
-impl Levels {
-    fn parse(input: &str) -> parse::Result<Self> {
+impl Parse for Levels {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (_, levels) = separated_list1(space1, u64)(input)?;
-        Ok(Self(levels))
+        let (rest, levels) = separated_list1(space1, u64)(input)?;
+        Ok((rest, Self(levels)))
     }
+}
 
+impl Levels {
     fn safe(&self) -> bool {
         let mut bounded: Result<()> = Ok(());
         let monotonic = self
@@ -96,7 +125,10 @@ mod test {
             8 6 4 4 1
             1 3 6 7 9
         "};
-        assert_eq!(num_safe_reports(example.lines().map(String::from))?, 2);
+        assert_eq!(
+            num_safe_reports(example.lines().map(String::from), Mode::Strict)?,
+            2
+        );
         Ok(())
     }
 }
@@ -1,6 +1,9 @@
 use std::cell::OnceCell;
 
-use common::parse::{self};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use itertools::Itertools;
 use nom::{character::complete::space1, multi::separated_list1};
 use thiserror::Error;
@@ -9,7 +12,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
     #[error("consecutive levels are equal")]
     ConsecutiveEquals,
@@ -17,11 +20,35 @@ pub enum Error {
     BigJump,
 }
 
-pub fn num_safe_reports(it: impl Iterator<Item = String>) -> Result<usize> {
-    Ok(it
-        .filter_map(|ref line| Levels::parse(line).ok())
-        .filter(Levels::safe)
-        .count())
+pub fn num_safe_reports(it: impl Iterator<Item = String>, mode: Mode) -> Result<usize> {
+    Solver::solve(Reports::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+    type Input = Reports;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Reports> {
+        Ok(Reports::parse(input.lines(), mode)?)
+    }
+
+    fn solve(reports: Reports) -> Result<usize> {
+        Ok(reports.0.iter().filter(|levels| levels.safe()).count())
+    }
+}
+
+pub struct Reports(Vec<Levels>);
+
+impl Reports {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> parse::Result<Self> {
+        Ok(Self(parse::lines(lines, mode, Levels::parse)?))
+    }
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -49,13 +76,15 @@ impl<'a> IntoIterator for &'a Levels {
     }
 }
 
-impl Levels {
-    fn parse(input: &str) -> parse::Result<Self> {
+impl Parse for Levels {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (_, levels) = separated_list1(space1, u64)(input)?;
-        Ok(Self(levels))
+        let (rest, levels) = separated_list1(space1, u64)(input)?;
+        Ok((rest, Self(levels)))
     }
+}
 
+impl Levels {
     fn safe(&self) -> bool {
         let mut tester = LevelsTester::new(&self.0);
         tester.any(|test| test)
@@ -133,7 +162,10 @@ mod test {
             8 6 4 4 1
             1 3 6 7 9
         "};
-        assert_eq!(num_safe_reports(example.lines().map(String::from))?, 4);
+        assert_eq!(
+            num_safe_reports(example.lines().map(String::from), Mode::Strict)?,
+            4
+        );
         Ok(())
     }
 }
@@ -1,11 +1,9 @@
-use std::io;
+use std::process::ExitCode;
 
-use aoc2024_02_2::{num_safe_reports, Result};
+use aoc2024_02_2::Solver;
+use common::cli;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     tracing_subscriber::fmt::init();
-    let lines = io::stdin().lines().map_while(|l| l.ok());
-    let answer = num_safe_reports(lines)?;
-    println!("Answer: {answer}");
-    Ok(())
+    cli::run::<Solver>()
 }
@@ -1,11 +1,9 @@
-use std::io::{self};
+use std::process::ExitCode;
 
-use aoc2024_03_1::{multiplication_sum, Result};
+use aoc2024_03_1::Solver;
+use common::cli;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     tracing_subscriber::fmt::init();
-    let stdin = io::read_to_string(io::stdin())?;
-    let answer = multiplication_sum(&stdin)?;
-    println!("Answer: {answer}");
-    Ok(())
+    cli::run::<Solver>()
 }
@@ -1,6 +1,10 @@
-use common::parse::{self, Parse};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use nom::{
     bytes::complete::tag,
+    error::context,
     sequence::{delimited, separated_pair},
 };
 use thiserror::Error;
@@ -9,16 +13,36 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
 
 pub fn multiplication_sum(input: &str) -> Result<u64> {
-    let active_sections = ActiveSections::parse(input);
-    let active_muls = active_sections.into_iter().flat_map(Muls::parse);
-    Ok(active_muls.into_iter().map(|mul| mul.tiplied()).sum())
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Mull It Over";
+    type Input = Muls;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, _mode: Mode) -> Result<Muls> {
+        let active_sections = ActiveSections::parse(input);
+        Ok(Muls(
+            active_sections.into_iter().flat_map(Muls::parse).collect(),
+        ))
+    }
+
+    fn solve(active_muls: Muls) -> Result<u64> {
+        Ok(active_muls.into_iter().map(|mul| mul.tiplied()).sum())
+    }
 }
 
 enum Section<'a> {
@@ -83,7 +107,7 @@ impl<'a> ActiveSections<'a> {
     }
 }
 
-struct Mul {
+pub struct Mul {
     x: u64,
     y: u64,
 }
@@ -95,16 +119,24 @@ impl Mul {
 }
 
 impl Parse for Mul {
-    fn parse(input: &str) -> nom::IResult<&str, Self> {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::char;
         use nom::character::complete::u64;
-        let (rest, (x, y)) =
-            delimited(tag("mul("), separated_pair(u64, char(','), u64), char(')'))(input)?;
+        let factors = separated_pair(
+            context("first factor", u64),
+            context("`,` between factors", char(',')),
+            context("second factor", u64),
+        );
+        let (rest, (x, y)) = delimited(
+            context("`mul(`", tag("mul(")),
+            factors,
+            context("closing `)` of mul", char(')')),
+        )(input)?;
         Ok((rest, Mul { x, y }))
     }
 }
 
-struct Muls(Vec<Mul>);
+pub struct Muls(Vec<Mul>);
 
 impl Muls {
     fn parse(input: &str) -> Self {
@@ -1,50 +1,46 @@
-use std::ops::AddAssign;
-
-use common::parse::{self};
+use common::{
+    geom::{Direction8, Point},
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
 pub fn num_xmas_hits(it: impl Iterator<Item = String>) -> Result<usize> {
-    let grid = Grid::from(it);
-    let xs = grid.find('X');
-    let mut searchers: Vec<_> = xs
-        .into_iter()
-        .flat_map(|coordinate| grid.searchers(&coordinate))
-        .collect();
-    Ok(searchers
-        .iter_mut()
-        .filter_map(|searcher| if searcher.hit() { Some(()) } else { None })
-        .count())
+    Solver::solve(Grid::from_lines(it, |ch| ch)?)
 }
 
-struct Row(Vec<char>);
+pub struct Solver;
 
-impl From<String> for Row {
-    fn from(value: String) -> Self {
-        Self(value.chars().collect())
-    }
-}
+impl Solution for Solver {
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Ceres Search";
+    type Input = Grid<char>;
+    type Answer = usize;
+    type Error = Error;
 
-impl IntoIterator for Row {
-    type Item = char;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+    fn parse(input: &str, _mode: Mode) -> Result<Grid<char>> {
+        Ok(input.parse()?)
     }
-}
 
-impl<'a> IntoIterator for &'a Row {
-    type Item = &'a char;
-    type IntoIter = std::slice::Iter<'a, char>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+    fn solve(grid: Grid<char>) -> Result<usize> {
+        let xs = grid.positions_of(&'X');
+        let mut searchers: Vec<_> = xs
+            .flat_map(|coordinate| searchers(&grid, coordinate))
+            .collect();
+        Ok(searchers
+            .iter_mut()
+            .filter_map(|searcher| if searcher.hit() { Some(()) } else { None })
+            .count())
     }
 }
 
@@ -88,29 +84,22 @@ impl PartialEq<char> for Xmas {
     }
 }
 
-enum Direction {
-    E,
-    NE,
-    N,
-    NW,
-    W,
-    SW,
-    S,
-    SE,
-}
-
 struct Searcher<'a> {
-    grid: &'a Grid,
-    position: Coordinate,
+    grid: &'a Grid<char>,
+    position: Point,
     xmas: Xmas,
-    direction: Direction,
+    direction: Direction8,
 }
 
 impl<'a> Searcher<'a> {
     fn hit(&'a mut self) -> bool {
         while self.xmas.next() {
-            self.position += &self.direction;
-            if self.xmas != self.grid.char(&self.position) {
+            self.position += self.direction;
+            if !self
+                .grid
+                .get(&self.position)
+                .is_some_and(|ch| self.xmas == *ch)
+            {
                 return false;
             }
         }
@@ -118,141 +107,16 @@ impl<'a> Searcher<'a> {
     }
 }
 
-struct Grid(Vec<Row>);
-
-impl Grid {
-    fn find(&self, char: char) -> Vec<Coordinate> {
-        self.into_iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.into_iter().enumerate().filter_map(move |(x, ch)| {
-                    if *ch == char {
-                        Some(Coordinate {
-                            x: x as i64,
-                            y: y as i64,
-                        })
-                    } else {
-                        None
-                    }
-                })
-            })
-            .collect()
-    }
-
-    fn char(&self, coordinate: &Coordinate) -> char {
-        self.0[coordinate.y as usize].0[coordinate.x as usize]
-    }
-
-    fn height(&self) -> usize {
-        self.0.len()
-    }
-
-    fn width(&self) -> usize {
-        self.0[0].0.len()
-    }
-
-    fn searchers(&self, Coordinate { x, y }: &Coordinate) -> Vec<Searcher> {
-        let mut directions = Vec::new();
-        if *x > 2 {
-            directions.push(Direction::W);
-            if *y > 2 {
-                directions.push(Direction::NW);
-            }
-            if *y < self.height() as i64 - 3 {
-                directions.push(Direction::SW);
-            }
-        }
-        if *x < self.width() as i64 - 3 {
-            directions.push(Direction::E);
-            if *y > 2 {
-                directions.push(Direction::NE);
-            }
-            if *y < self.height() as i64 - 3 {
-                directions.push(Direction::SE);
-            }
-        }
-        if *y > 2 {
-            directions.push(Direction::N);
-        }
-        if *y < self.height() as i64 - 3 {
-            directions.push(Direction::S);
-        }
-        directions
-            .into_iter()
-            .map(|direction| Searcher {
-                grid: self,
-                direction,
-                xmas: Xmas::default(),
-                position: Coordinate { x: *x, y: *y },
-            })
-            .collect()
-    }
-}
-
-impl IntoIterator for Grid {
-    type Item = Row;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
-
-impl<'a> IntoIterator for &'a Grid {
-    type Item = &'a Row;
-    type IntoIter = std::slice::Iter<'a, Row>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
-    }
-}
-
-impl<I> From<I> for Grid
-where
-    I: Iterator<Item = String>,
-{
-    fn from(value: I) -> Self {
-        Self(value.map(Row::from).collect())
-    }
-}
-
-#[derive(Debug)]
-struct Coordinate {
-    x: i64,
-    y: i64,
-}
-
-impl AddAssign<&Direction> for Coordinate {
-    fn add_assign(&mut self, rhs: &Direction) {
-        match rhs {
-            Direction::E => {
-                self.x += 1;
-            }
-            Direction::NE => {
-                self.y -= 1;
-                self.x += 1;
-            }
-            Direction::N => {
-                self.y -= 1;
-            }
-            Direction::NW => {
-                self.y -= 1;
-                self.x -= 1;
-            }
-            Direction::W => {
-                self.x -= 1;
-            }
-            Direction::SW => {
-                self.x -= 1;
-                self.y += 1;
-            }
-            Direction::S => {
-                self.y += 1;
-            }
-            Direction::SE => {
-                self.x += 1;
-                self.y += 1;
-            }
-        }
-    }
+fn searchers(grid: &Grid<char>, position: Point) -> Vec<Searcher<'_>> {
+    Direction8::ALL
+        .into_iter()
+        .map(|direction| Searcher {
+            grid,
+            direction,
+            xmas: Xmas::default(),
+            position,
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -1,11 +1,9 @@
-use std::io;
+use std::process::ExitCode;
 
-use aoc2024_04_1::{num_xmas_hits, Result};
+use aoc2024_04_1::Solver;
+use common::cli;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     tracing_subscriber::fmt::init();
-    let lines = io::stdin().lines().map_while(|l| l.ok());
-    let answer = num_xmas_hits(lines)?;
-    println!("Answer: {answer}");
-    Ok(())
+    cli::run::<Solver>()
 }
@@ -1,56 +1,54 @@
 use std::fmt::Display;
 
-use common::parse::{self};
+use common::{
+    geom::{Direction8, Point},
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
 pub fn num_xmas_hits(it: impl Iterator<Item = String>) -> Result<usize> {
-    let grid = Grid::from(it);
-    let candidate_as = grid.find('A');
-    let mut searchers: Vec<_> = candidate_as
-        .into_iter()
-        .filter_map(|coordinate| grid.searcher(&coordinate))
-        .collect();
-    Ok(searchers
-        .iter_mut()
-        .filter_map(|searcher| if searcher.hit() { Some(()) } else { None })
-        .count())
+    Solver::solve(Grid::from_lines(it, |ch| ch)?)
 }
 
-struct Row(Vec<char>);
+pub struct Solver;
 
-impl From<String> for Row {
-    fn from(value: String) -> Self {
-        Self(value.chars().collect())
-    }
-}
+impl Solution for Solver {
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Ceres Search";
+    type Input = Grid<char>;
+    type Answer = usize;
+    type Error = Error;
 
-impl IntoIterator for Row {
-    type Item = char;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+    fn parse(input: &str, _mode: Mode) -> Result<Grid<char>> {
+        Ok(input.parse()?)
     }
-}
 
-impl<'a> IntoIterator for &'a Row {
-    type Item = &'a char;
-    type IntoIter = std::slice::Iter<'a, char>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+    fn solve(grid: Grid<char>) -> Result<usize> {
+        let candidate_as = grid.positions_of(&'A');
+        let mut searchers: Vec<_> = candidate_as
+            .filter_map(|coordinate| searcher(&grid, &coordinate))
+            .collect();
+        Ok(searchers
+            .iter_mut()
+            .filter_map(|searcher| if searcher.hit() { Some(()) } else { None })
+            .count())
     }
 }
 
 struct Searcher<'a> {
-    grid: &'a Grid,
-    position: Coordinate,
+    grid: &'a Grid<char>,
+    position: Point,
 }
 
 impl Display for Searcher<'_> {
@@ -58,7 +56,7 @@ impl Display for Searcher<'_> {
         let mut out = String::new();
         for y in self.position.y - 1..=self.position.y + 1 {
             for x in self.position.x - 1..=self.position.x + 1 {
-                out.push(self.grid.char(&Coordinate { x, y }));
+                out.push(self.grid[Point { x, y }]);
             }
             out.push('\n');
         }
@@ -68,26 +66,25 @@ impl Display for Searcher<'_> {
 
 impl<'a> Searcher<'a> {
     fn hit(&'a mut self) -> bool {
-        let Coordinate { x, y } = self.position;
         let diagonals = [
             [
-                Coordinate { x: x - 1, y: y - 1 },
-                Coordinate { x: x + 1, y: y + 1 },
+                self.position + Direction8::NW,
+                self.position + Direction8::SE,
             ],
             [
-                Coordinate { x: x + 1, y: y - 1 },
-                Coordinate { x: x - 1, y: y + 1 },
+                self.position + Direction8::NE,
+                self.position + Direction8::SW,
             ],
         ];
         for diagonal in diagonals {
-            match self.grid.char(&diagonal[0]) {
+            match self.grid[diagonal[0]] {
                 'M' => {
-                    if self.grid.char(&diagonal[1]) != 'S' {
+                    if self.grid[diagonal[1]] != 'S' {
                         return false;
                     }
                 }
                 'S' => {
-                    if self.grid.char(&diagonal[1]) != 'M' {
+                    if self.grid[diagonal[1]] != 'M' {
                         return false;
                     }
                 }
@@ -100,82 +97,17 @@ impl<'a> Searcher<'a> {
     }
 }
 
-struct Grid(Vec<Row>);
-
-impl Grid {
-    fn find(&self, char: char) -> Vec<Coordinate> {
-        self.into_iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.into_iter().enumerate().filter_map(move |(x, ch)| {
-                    if *ch == char {
-                        Some(Coordinate {
-                            x: x as i64,
-                            y: y as i64,
-                        })
-                    } else {
-                        None
-                    }
-                })
-            })
-            .collect()
-    }
-
-    fn char(&self, coordinate: &Coordinate) -> char {
-        self.0[coordinate.y as usize].0[coordinate.x as usize]
-    }
-
-    fn height(&self) -> usize {
-        self.0.len()
-    }
-
-    fn width(&self) -> usize {
-        self.0[0].0.len()
-    }
-
-    fn searcher(&self, Coordinate { x, y }: &Coordinate) -> Option<Searcher> {
-        if *x > 0 && *x < self.width() as i64 - 1 && *y > 0 && *y < self.height() as i64 - 1 {
-            Some(Searcher {
-                grid: self,
-                position: Coordinate { x: *x, y: *y },
-            })
-        } else {
-            None
-        }
-    }
-}
-
-impl IntoIterator for Grid {
-    type Item = Row;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
-
-impl<'a> IntoIterator for &'a Grid {
-    type Item = &'a Row;
-    type IntoIter = std::slice::Iter<'a, Row>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
-    }
-}
-
-impl<I> From<I> for Grid
-where
-    I: Iterator<Item = String>,
-{
-    fn from(value: I) -> Self {
-        Self(value.map(Row::from).collect())
+fn searcher<'a>(grid: &'a Grid<char>, Point { x, y }: &Point) -> Option<Searcher<'a>> {
+    if *x > 0 && *x < grid.width() as i64 - 1 && *y > 0 && *y < grid.height() as i64 - 1 {
+        Some(Searcher {
+            grid,
+            position: Point { x: *x, y: *y },
+        })
+    } else {
+        None
     }
 }
 
-#[derive(Debug)]
-struct Coordinate {
-    x: i64,
-    y: i64,
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -3,32 +3,64 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-use common::parse::{self, Parse};
-use nom::{multi::separated_list1, sequence::separated_pair};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{error::context, multi::separated_list1, sequence::separated_pair};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
-pub fn sum_middle_fixed_updates(mut it: impl Iterator<Item = String>) -> Result<u64> {
-    let rules: Rules = it
-        .by_ref()
-        .map_while(|line| {
-            Precedence::parse(&line)
-                .ok()
-                .map(|(_, precedence)| precedence)
+pub fn sum_middle_fixed_updates(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Manual::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 5;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Print Queue";
+    type Input = Manual;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Manual> {
+        Ok(Manual::parse(input.lines(), mode)?)
+    }
+
+    fn solve(Manual { rules, updates }: Manual) -> Result<u64> {
+        let invalid = updates.into_iter().filter(|pages| !rules.validate(pages));
+        Ok(invalid.map(|pages| rules.sort(pages).middle()).sum())
+    }
+}
+
+pub struct Manual {
+    rules: Rules,
+    updates: Vec<Pages>,
+}
+
+impl Manual {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> parse::Result<Self> {
+        let mut lines = lines.enumerate().map(|(idx, line)| (idx + 1, line));
+        let precedences = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.as_ref().is_empty());
+        let rules = parse::numbered_lines(precedences, mode, Precedence::parse);
+        let updates = parse::numbered_lines(lines, mode, Pages::parse);
+        let (rules, updates) = parse::both(rules, updates)?;
+        Ok(Self {
+            rules: rules.into(),
+            updates,
         })
-        .into();
-    let _ = it.by_ref().skip_while(|line| line == "\n");
-    let invalid = it
-        .filter_map(|ref line| Pages::parse(line).ok().map(|(_, pages)| pages))
-        .filter(|pages| !rules.validate(pages));
-    Ok(invalid.map(|pages| rules.sort(pages).middle()).sum())
+    }
 }
 
 struct Pages(Vec<u64>);
@@ -56,9 +88,10 @@ impl<'a> IntoIterator for &'a Pages {
 }
 
 impl Parse for Pages {
-    fn parse(input: &str) -> nom::IResult<&str, Self> {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::{char, u64};
-        let (rest, pages) = separated_list1(char(','), u64)(input)?;
+        let (rest, pages) =
+            separated_list1(char(','), context("page number in update", u64))(input)?;
         Ok((rest, Self(pages)))
     }
 }
@@ -122,10 +155,14 @@ where
 }
 
 impl Parse for Precedence {
-    fn parse(input: &str) -> nom::IResult<&str, Self> {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::char;
         use nom::character::complete::u64;
-        let (rest, (predecessor, successor)) = separated_pair(u64, char('|'), u64)(input)?;
+        let (rest, (predecessor, successor)) = separated_pair(
+            context("page number in precedence rule", u64),
+            context("`|` separator in precedence rule", char('|')),
+            context("page number in precedence rule", u64),
+        )(input)?;
         Ok((
             rest,
             Self {
@@ -174,7 +211,7 @@ mod test {
             97,13,75,29,47
         "};
         assert_eq!(
-            sum_middle_fixed_updates(example.lines().map(String::from))?,
+            sum_middle_fixed_updates(example.lines().map(String::from), Mode::Strict)?,
             123
         );
         Ok(())
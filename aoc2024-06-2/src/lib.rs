@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use common::{
+    geom::{Direction, Point},
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("missing start position ('^')")]
+    MissingStartPosition,
+}
+
+pub fn num_looping_obstructions(it: impl Iterator<Item = String>) -> Result<usize> {
+    Solver::solve(Lab::parse(it)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 6;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Guard Gallivant";
+    type Input = Lab;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, _mode: Mode) -> Result<Lab> {
+        Lab::parse(input.lines())
+    }
+
+    fn solve(Lab { map, start }: Lab) -> Result<usize> {
+        let mut guard = Guard {
+            position: start,
+            direction: Direction::N,
+            map: &map,
+            obstruction: None,
+        };
+
+        // The guard's route up to the first visit of a cell doesn't depend on
+        // an obstruction there, so each candidate is tested from that point.
+        let mut candidates = HashSet::from([start]);
+        let mut looping = 0;
+
+        loop {
+            let before = guard.clone();
+            let Some((position, _)) = guard.step() else {
+                break;
+            };
+            if candidates.insert(position) {
+                let trial = Guard {
+                    obstruction: Some(position),
+                    ..before
+                };
+                if trial.loops() {
+                    looping += 1;
+                }
+            }
+        }
+
+        Ok(looping)
+    }
+}
+
+pub struct Lab {
+    map: Grid<char>,
+    start: Point,
+}
+
+impl Lab {
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>) -> Result<Self> {
+        let map = Grid::from_lines(rows, |ch| ch)?;
+        let Some(start) = map.find(&'^') else {
+            return Err(Error::MissingStartPosition);
+        };
+        Ok(Self { map, start })
+    }
+}
+
+#[derive(Clone)]
+struct Guard<'a> {
+    position: Point,
+    direction: Direction,
+    map: &'a Grid<char>,
+    obstruction: Option<Point>,
+}
+
+impl Guard<'_> {
+    fn blocked(&self, position: &Point) -> bool {
+        self.map.get(position) == Some(&'#') || self.obstruction.as_ref() == Some(position)
+    }
+
+    /// Turns right in place if blocked, otherwise moves forward one cell.
+    fn step(&mut self) -> Option<(Point, Direction)> {
+        let next = self.position + self.direction;
+        if self.blocked(&next) {
+            self.direction = self.direction.turn_right();
+        } else {
+            self.position = next;
+        }
+        if self.map.contains(&self.position) {
+            return Some((self.position, self.direction));
+        }
+        None
+    }
+
+    fn loops(mut self) -> bool {
+        // Any loop involves turning, so it's enough to remember the turns.
+        let mut turns = HashSet::new();
+        loop {
+            let direction = self.direction;
+            match self.step() {
+                None => return false,
+                Some(state) if state.1 != direction => {
+                    if !turns.insert(state) {
+                        return true;
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            ....#.....
+            .........#
+            ..........
+            ..#.......
+            .......#..
+            ..........
+            .#..^.....
+            ........#.
+            #.........
+            ......#...
+        "};
+        assert_eq!(
+            num_looping_obstructions(example.lines().map(String::from))?,
+            6
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn turns_twice_in_a_corner() -> Result<()> {
+        let example = indoc! {"
+            .#.
+            .^#
+            ...
+        "};
+        assert_eq!(
+            num_looping_obstructions(example.lines().map(String::from))?,
+            0
+        );
+        Ok(())
+    }
+}
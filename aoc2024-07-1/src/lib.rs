@@ -1,6 +1,9 @@
-use common::parse::{self, Parse};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use nom::{
-    bytes::complete::tag, character::complete::space1, multi::separated_list1,
+    bytes::complete::tag, character::complete::space1, error::context, multi::separated_list1,
     sequence::separated_pair,
 };
 use thiserror::Error;
@@ -9,25 +12,49 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
-pub fn sum_achievable_test_values(it: impl Iterator<Item = String>) -> Result<u64> {
-    Ok(it
-        .filter_map(|ref line| {
-            CalibrationEquation::parse(line)
-                .ok()
-                .map(|(_, equation)| equation)
-        })
-        .filter_map(|equation| {
-            if equation.valid() {
-                Some(equation.answer)
-            } else {
-                None
-            }
-        })
-        .sum())
+pub fn sum_achievable_test_values(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Calibration::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 7;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Bridge Repair";
+    type Input = Calibration;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Calibration> {
+        Ok(Calibration::parse(input.lines(), mode)?)
+    }
+
+    fn solve(calibration: Calibration) -> Result<u64> {
+        Ok(calibration
+            .0
+            .into_iter()
+            .filter_map(|equation| {
+                if equation.valid() {
+                    Some(equation.answer)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+}
+
+pub struct Calibration(Vec<CalibrationEquation>);
+
+impl Calibration {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> parse::Result<Self> {
+        Ok(Self(parse::lines(lines, mode, CalibrationEquation::parse)?))
+    }
 }
 
 struct CalibrationEquation {
@@ -42,9 +69,13 @@ impl CalibrationEquation {
 }
 
 impl Parse for CalibrationEquation {
-    fn parse(input: &str) -> nom::IResult<&str, Self> {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (rest, (answer, variables)) = separated_pair(u64, tag(": "), Variables::parse)(input)?;
+        let (rest, (answer, variables)) = separated_pair(
+            context("test value", u64),
+            context("`: ` after test value", tag(": ")),
+            Variables::parse,
+        )(input)?;
         Ok((rest, Self { answer, variables }))
     }
 }
@@ -64,7 +95,7 @@ fn find_answer(answer: u64, variables: &[u64]) -> bool {
     if find_answer(answer - last, rest) {
         return true;
     }
-    if answer % last == 0 {
+    if answer.is_multiple_of(*last) {
         return find_answer(answer / last, rest);
     }
     false
@@ -87,9 +118,9 @@ impl<'a> IntoIterator for &'a Variables {
 }
 
 impl Parse for Variables {
-    fn parse(input: &str) -> nom::IResult<&str, Self> {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (rest, variables) = separated_list1(space1, u64)(input)?;
+        let (rest, variables) = separated_list1(space1, context("operand", u64))(input)?;
         Ok((rest, Self(variables)))
     }
 }
@@ -113,9 +144,24 @@ mod test {
             292: 11 6 16 20
         "};
         assert_eq!(
-            sum_achievable_test_values(example.lines().map(String::from))?,
+            sum_achievable_test_values(example.lines().map(String::from), Mode::Strict)?,
             3749
         );
         Ok(())
     }
+
+    #[test]
+    fn trailing_garbage() -> Result<()> {
+        let example = indoc! {"
+            190: 10 19
+            83: 17 5x
+        "};
+        let lines = || example.lines().map(String::from);
+        assert!(matches!(
+            sum_achievable_test_values(lines(), Mode::Strict),
+            Err(Error::Parse(parse::Error::Diagnostics(errors))) if errors[0].column == 9
+        ));
+        assert_eq!(sum_achievable_test_values(lines(), Mode::Lenient)?, 190);
+        Ok(())
+    }
 }
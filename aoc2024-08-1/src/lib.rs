@@ -1,9 +1,13 @@
-use common::parse::{self};
+use common::{
+    geom::Point,
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
 use itertools::Itertools;
 use std::{
     collections::{HashMap, HashSet},
     iter,
-    ops::{Add, Sub},
 };
 use thiserror::Error;
 
@@ -11,64 +15,51 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
 pub fn num_antinodes(it: impl Iterator<Item = String>) -> Result<usize> {
-    let mut world = Map::default();
-    for row in it {
-        world.push_row(&row);
-    }
-    Ok(world.antinodes().count())
+    Solver::solve(Map::parse(it)?)
 }
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
-struct Position {
-    x: i64,
-    y: i64,
-}
+pub struct Solver;
 
-impl Add for &Position {
-    type Output = Position;
-    fn add(self, rhs: Self) -> Self::Output {
-        Position {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+impl Solution for Solver {
+    const DAY: u8 = 8;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Resonant Collinearity";
+    type Input = Map;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, _mode: Mode) -> Result<Map> {
+        Map::parse(input.lines())
     }
-}
 
-impl Sub for &Position {
-    type Output = Position;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Position {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
+    fn solve(world: Map) -> Result<usize> {
+        Ok(world.antinodes().count())
     }
 }
 
-#[derive(Default)]
-struct Map {
+pub struct Map {
+    grid: Grid<char>,
     antenae: HashMap<char, Antenae>,
-    width: i64,
-    height: i64,
 }
 
-struct Antenae(HashSet<Position>);
+struct Antenae(HashSet<Point>);
 
 impl Antenae {
-    fn antinodes(&self) -> impl Iterator<Item = Position> + '_ {
+    fn antinodes(&self) -> impl Iterator<Item = Point> + '_ {
         self.0.iter().tuple_combinations().flat_map(|(a, b)| {
-            let delta = a - b;
-            iter::once(a + &delta).chain(iter::once(b - &delta))
+            let delta = *a - *b;
+            iter::once(*a + delta).chain(iter::once(*b - delta))
         })
     }
 }
 
 impl Map {
-    fn antinodes(&self) -> impl Iterator<Item = Position> + '_ {
+    fn antinodes(&self) -> impl Iterator<Item = Point> + '_ {
         self.antenae
             .values()
             .flat_map(Antenae::antinodes)
@@ -76,25 +67,21 @@ impl Map {
             .unique()
     }
 
-    fn push_row(&mut self, row: &str) {
-        for (x, ch) in row.chars().enumerate().filter(|(_, ch)| *ch != '.') {
-            let pos = Position {
-                x: x as i64,
-                y: self.height,
-            };
-            self.antenae
-                .entry(ch)
-                .and_modify(|locations| {
-                    locations.0.insert(pos);
-                })
-                .or_insert_with(|| Antenae(HashSet::from([pos])));
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>) -> Result<Self> {
+        let grid = Grid::from_lines(rows, |ch| ch)?;
+        let mut antenae = HashMap::new();
+        for (position, ch) in grid.iter().filter(|(_, ch)| **ch != '.') {
+            antenae
+                .entry(*ch)
+                .or_insert_with(|| Antenae(HashSet::new()))
+                .0
+                .insert(position);
         }
-        self.width = row.len() as i64;
-        self.height += 1;
+        Ok(Self { grid, antenae })
     }
 
-    fn contains(&self, Position { x, y }: &Position) -> bool {
-        x < &self.width && y < &self.height && *x >= 0 && *y >= 0
+    fn contains(&self, position: &Point) -> bool {
+        self.grid.contains(position)
     }
 }
 
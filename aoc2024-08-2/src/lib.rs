@@ -1,9 +1,13 @@
-use common::parse::{self};
+use common::{
+    geom::Point,
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
 use itertools::Itertools;
 use std::{
     collections::{HashMap, HashSet},
     iter,
-    ops::{Add, AddAssign, Sub},
 };
 use thiserror::Error;
 
@@ -11,70 +15,50 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
 pub fn num_antinodes(it: impl Iterator<Item = String>) -> Result<usize> {
-    let mut world = Map::default();
-    for row in it {
-        world.push_row(&row);
-    }
-    Ok(world.antinodes().count())
+    Solver::solve(Map::parse(it)?)
 }
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
-struct Position {
-    x: i64,
-    y: i64,
-}
+pub struct Solver;
 
-impl Add for &Position {
-    type Output = Position;
-    fn add(self, rhs: Self) -> Self::Output {
-        Position {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
+impl Solution for Solver {
+    const DAY: u8 = 8;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Resonant Collinearity";
+    type Input = Map;
+    type Answer = usize;
+    type Error = Error;
 
-impl AddAssign for Position {
-    fn add_assign(&mut self, Self { x, y }: Self) {
-        self.x += x;
-        self.y += y;
+    fn parse(input: &str, _mode: Mode) -> Result<Map> {
+        Map::parse(input.lines())
     }
-}
 
-impl Sub for &Position {
-    type Output = Position;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Position {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
+    fn solve(world: Map) -> Result<usize> {
+        Ok(world.antinodes().count())
     }
 }
 
-#[derive(Default)]
-struct Map {
+pub struct Map {
+    grid: Grid<char>,
     antenae: HashMap<char, Antenae>,
-    width: i64,
-    height: i64,
 }
 
-struct Antenae(HashSet<Position>);
+struct Antenae(HashSet<Point>);
 
 impl Antenae {
-    fn antinodes(&self, bounds: Bounds) -> impl Iterator<Item = Position> + '_ {
+    fn antinodes<'a>(&'a self, grid: &'a Grid<char>) -> impl Iterator<Item = Point> + 'a {
         self.0.iter().tuple_combinations().flat_map(move |(a, b)| {
-            let fwd_delta = a - b;
-            let rev_delta = b - a;
-            let mut fwd_pos = a - &fwd_delta;
-            let mut rev_pos = b - &rev_delta;
+            let fwd_delta = *a - *b;
+            let rev_delta = -fwd_delta;
+            let mut fwd_pos = *a - fwd_delta;
+            let mut rev_pos = *b - rev_delta;
             let fwd = iter::from_fn(move || {
                 fwd_pos += fwd_delta;
-                if bounds.contains(&fwd_pos) {
+                if grid.contains(&fwd_pos) {
                     Some(fwd_pos)
                 } else {
                     None
@@ -82,7 +66,7 @@ impl Antenae {
             });
             let rev = iter::from_fn(move || {
                 rev_pos += rev_delta;
-                if bounds.contains(&rev_pos) {
+                if grid.contains(&rev_pos) {
                     Some(rev_pos)
                 } else {
                     None
@@ -94,48 +78,25 @@ impl Antenae {
     }
 }
 
-#[derive(Clone, Copy)]
-struct Bounds {
-    x: i64,
-    y: i64,
-}
-
-impl Bounds {
-    fn contains(&self, Position { x, y }: &Position) -> bool {
-        x < &self.x && y < &self.y && *x >= 0 && *y >= 0
-    }
-}
-
 impl Map {
-    fn antinodes(&self) -> impl Iterator<Item = Position> + '_ {
+    fn antinodes(&self) -> impl Iterator<Item = Point> + '_ {
         self.antenae
             .values()
-            .flat_map(|nodes| Antenae::antinodes(nodes, self.bounds()))
+            .flat_map(|nodes| Antenae::antinodes(nodes, &self.grid))
             .unique()
     }
 
-    fn push_row(&mut self, row: &str) {
-        for (x, ch) in row.chars().enumerate().filter(|(_, ch)| *ch != '.') {
-            let pos = Position {
-                x: x as i64,
-                y: self.height,
-            };
-            self.antenae
-                .entry(ch)
-                .and_modify(|locations| {
-                    locations.0.insert(pos);
-                })
-                .or_insert_with(|| Antenae(HashSet::from([pos])));
-        }
-        self.width = row.len() as i64;
-        self.height += 1;
-    }
-
-    fn bounds(&self) -> Bounds {
-        Bounds {
-            x: self.width,
-            y: self.height,
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>) -> Result<Self> {
+        let grid = Grid::from_lines(rows, |ch| ch)?;
+        let mut antenae = HashMap::new();
+        for (position, ch) in grid.iter().filter(|(_, ch)| **ch != '.') {
+            antenae
+                .entry(*ch)
+                .or_insert_with(|| Antenae(HashSet::new()))
+                .0
+                .insert(position);
         }
+        Ok(Self { grid, antenae })
     }
 }
 
@@ -0,0 +1,97 @@
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{character::complete::satisfy, combinator::map, error::context, multi::many1};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+pub fn filesystem_checksum(input: &str) -> Result<usize> {
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 9;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Disk Fragmenter";
+    type Input = DiskMap;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<DiskMap> {
+        Ok(parse::complete(input, mode, DiskMap::parse)?)
+    }
+
+    fn solve(disk_map: DiskMap) -> Result<usize> {
+        let mut blocks = disk_map.blocks();
+        let (mut free, mut last) = (0, blocks.len());
+        loop {
+            while free < last && blocks[free].is_some() {
+                free += 1;
+            }
+            while last > free && blocks[last - 1].is_none() {
+                last -= 1;
+            }
+            if last - free < 2 {
+                break;
+            }
+            blocks.swap(free, last - 1);
+        }
+        Ok(blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(position, id)| id.map(|id| position * id))
+            .sum())
+    }
+}
+
+/// Alternating file and free-space lengths, starting with file 0.
+pub struct DiskMap(Vec<usize>);
+
+impl DiskMap {
+    /// The file id stored in each block, `None` for free blocks.
+    fn blocks(&self) -> Vec<Option<usize>> {
+        self.0
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, &len)| {
+                let id = (idx % 2 == 0).then_some(idx / 2);
+                std::iter::repeat_n(id, len)
+            })
+            .collect()
+    }
+}
+
+impl Parse for DiskMap {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let digit = map(satisfy(|ch| ch.is_ascii_digit()), |ch| {
+            ch as usize - '0' as usize
+        });
+        let (rest, lengths) = many1(context("disk map digit", digit))(input)?;
+        Ok((rest, Self(lengths)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            2333133121414131402
+        "};
+        assert_eq!(filesystem_checksum(example)?, 1928);
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{character::complete::satisfy, combinator::map, error::context, multi::many1};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+pub fn filesystem_checksum(input: &str) -> Result<usize> {
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 9;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Disk Fragmenter";
+    type Input = DiskMap;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<DiskMap> {
+        Ok(parse::complete(input, mode, DiskMap::parse)?)
+    }
+
+    fn solve(disk_map: DiskMap) -> Result<usize> {
+        let (mut files, mut free) = disk_map.layout();
+        for file in files.iter_mut().rev() {
+            if let Some(span) = free.take_leftmost(file.len, file.start) {
+                file.start = span.start;
+                free.insert(Span {
+                    start: span.start + file.len,
+                    len: span.len - file.len,
+                });
+            }
+        }
+        Ok(files.iter().map(File::checksum).sum())
+    }
+}
+
+/// Alternating file and free-space lengths, starting with file 0.
+pub struct DiskMap(Vec<usize>);
+
+impl DiskMap {
+    fn layout(&self) -> (Vec<File>, FreeSpans) {
+        let mut files = Vec::new();
+        let mut free = FreeSpans::default();
+        let mut start = 0;
+        for (idx, &len) in self.0.iter().enumerate() {
+            if idx % 2 == 0 {
+                files.push(File {
+                    id: idx / 2,
+                    start,
+                    len,
+                });
+            } else {
+                free.insert(Span { start, len });
+            }
+            start += len;
+        }
+        (files, free)
+    }
+}
+
+impl Parse for DiskMap {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let digit = map(satisfy(|ch| ch.is_ascii_digit()), |ch| {
+            ch as usize - '0' as usize
+        });
+        let (rest, lengths) = many1(context("disk map digit", digit))(input)?;
+        Ok((rest, Self(lengths)))
+    }
+}
+
+struct File {
+    id: usize,
+    start: usize,
+    len: usize,
+}
+
+impl File {
+    fn checksum(&self) -> usize {
+        self.id * (self.start..self.start + self.len).sum::<usize>()
+    }
+}
+
+struct Span {
+    start: usize,
+    len: usize,
+}
+
+/// Free spans bucketed by length, each bucket a min-heap of start positions,
+/// so the leftmost span that fits a file is found without scanning the disk.
+#[derive(Default)]
+struct FreeSpans([BinaryHeap<Reverse<usize>>; 10]);
+
+impl FreeSpans {
+    fn insert(&mut self, span: Span) {
+        if span.len > 0 {
+            self.0[span.len].push(Reverse(span.start));
+        }
+    }
+
+    /// Removes the leftmost span of at least `len` blocks starting before
+    /// `before`.
+    fn take_leftmost(&mut self, len: usize, before: usize) -> Option<Span> {
+        let (len, Reverse(start)) = (len..self.0.len())
+            .filter_map(|len| self.0[len].peek().map(|&start| (len, start)))
+            .filter(|&(_, Reverse(start))| start < before)
+            .min_by_key(|&(_, Reverse(start))| start)?;
+        self.0[len].pop();
+        Some(Span { start, len })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            2333133121414131402
+        "};
+        assert_eq!(filesystem_checksum(example)?, 2858);
+        Ok(())
+    }
+}
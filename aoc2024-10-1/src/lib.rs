@@ -1,90 +1,66 @@
 use std::collections::HashSet;
 
-use common::parse::{self};
+use common::{
+    geom::Point,
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
 pub fn sum_trailhead_scores(it: impl Iterator<Item = String>) -> Result<usize> {
-    let map: Map = it.into();
-    let bases = map.basecamps();
-    Ok(bases
-        .iter()
-        .map(|base| map.peaks_reachable_from(base).len())
-        .sum())
+    Solver::solve(Map::parse(it)?)
 }
 
-struct Row(Vec<u64>);
+pub struct Solver;
 
-impl IntoIterator for Row {
-    type Item = u64;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
+impl Solution for Solver {
+    const DAY: u8 = 10;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Hoof It";
+    type Input = Map;
+    type Answer = usize;
+    type Error = Error;
 
-impl<'a> IntoIterator for &'a Row {
-    type Item = &'a u64;
-    type IntoIter = std::slice::Iter<'a, u64>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+    fn parse(input: &str, _mode: Mode) -> Result<Map> {
+        Map::parse(input.lines())
     }
-}
 
-impl From<&str> for Row {
-    fn from(value: &str) -> Self {
-        Self(
-            value
-                .chars()
-                .filter_map(|ch| ch.to_digit(10).map(|i| i as u64))
-                .collect(),
-        )
+    fn solve(map: Map) -> Result<usize> {
+        let bases = map.basecamps();
+        Ok(bases
+            .iter()
+            .map(|base| map.peaks_reachable_from(base).len())
+            .sum())
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Coordinate {
-    x: i64,
-    y: i64,
-}
-
-struct Map(Vec<Row>);
+pub struct Map(Grid<Option<u64>>);
 
 impl Map {
-    fn basecamps(&self) -> HashSet<Coordinate> {
-        let mut basecamps = HashSet::new();
-        for (y, row) in self.0.iter().enumerate() {
-            for (x, col) in row.into_iter().enumerate() {
-                if *col == 0 {
-                    basecamps.insert(Coordinate {
-                        x: x as i64,
-                        y: y as i64,
-                    });
-                }
-            }
-        }
-        basecamps
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>) -> Result<Self> {
+        Ok(Self(Grid::from_lines(rows, |ch| {
+            ch.to_digit(10).map(u64::from)
+        })?))
     }
 
-    fn get_elevation(&self, coord: &Coordinate) -> Option<u64> {
-        let Coordinate { x, y } = coord;
-        let Ok::<usize, _>(x) = (*x).try_into() else {
-            return None;
-        };
-        let Ok::<usize, _>(y) = (*y).try_into() else {
-            return None;
-        };
-        self.0.get(y).and_then(|row| row.0.get(x).copied())
+    fn basecamps(&self) -> HashSet<Point> {
+        self.0.positions_of(&Some(0)).collect()
     }
 
-    fn neighbours(&self, coord: &Coordinate) -> Vec<Coordinate> {
+    fn get_elevation(&self, coord: &Point) -> Option<u64> {
+        self.0.get(coord).copied().flatten()
+    }
+
+    fn neighbours(&self, coord: &Point) -> Vec<Point> {
         let mut ret = Vec::new();
         let Some(elevation) = self.get_elevation(coord) else {
             return ret;
@@ -92,14 +68,7 @@ impl Map {
         if elevation == 9 {
             return ret;
         }
-        let Coordinate { x, y } = coord;
-        let adjacents = [
-            Coordinate { x: x - 1, y: *y },
-            Coordinate { x: x + 1, y: *y },
-            Coordinate { x: *x, y: y - 1 },
-            Coordinate { x: *x, y: y + 1 },
-        ];
-        for neighbour in adjacents {
+        for neighbour in self.0.neighbours4(*coord) {
             if let Some(neighbour_elevation) = self.get_elevation(&neighbour) {
                 if neighbour_elevation == elevation + 1 {
                     ret.push(neighbour);
@@ -109,7 +78,7 @@ impl Map {
         ret
     }
 
-    fn peaks_reachable_from(&self, coord: &Coordinate) -> HashSet<Coordinate> {
+    fn peaks_reachable_from(&self, coord: &Point) -> HashSet<Point> {
         let Some(elevation) = self.get_elevation(coord) else {
             return HashSet::new();
         };
@@ -125,32 +94,6 @@ impl Map {
     }
 }
 
-impl<I, S> From<I> for Map
-where
-    I: Iterator<Item = S>,
-    S: AsRef<str>,
-{
-    fn from(lines: I) -> Self {
-        Self(lines.map(|line| Row::from(line.as_ref())).collect())
-    }
-}
-
-impl IntoIterator for Map {
-    type Item = Row;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
-
-impl<'a> IntoIterator for &'a Map {
-    type Item = &'a Row;
-    type IntoIter = std::slice::Iter<'a, Row>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,26 +1,47 @@
 use std::collections::VecDeque;
 
-use common::parse::{self, Parse};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use ilog::IntLog;
-use nom::{character::streaming::space1, multi::separated_list1};
+use nom::{character::complete::space1, error::context, multi::separated_list1};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
 
 pub fn num_stones(input: &str) -> Result<usize> {
-    let (_, mut stones) = Stones::parse(input).map_err(|e| Error::Parse(e.into()))?;
-    for _ in 0..25 {
-        stones.blink();
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 11;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    type Input = Stones;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Stones> {
+        Ok(parse::complete(input, mode, Stones::parse)?)
+    }
+
+    fn solve(mut stones: Stones) -> Result<usize> {
+        for _ in 0..25 {
+            stones.blink();
+        }
+        Ok(stones.len())
     }
-    Ok(stones.len())
 }
 
 #[derive(Debug)]
@@ -51,7 +72,7 @@ impl Stone {
 }
 
 #[derive(Debug)]
-struct Stones(VecDeque<Stone>);
+pub struct Stones(VecDeque<Stone>);
 
 impl Stones {
     fn blink(&mut self) {
@@ -68,9 +89,9 @@ impl Stones {
 }
 
 impl Parse for Stones {
-    fn parse(input: &str) -> nom::IResult<&str, Self> {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (rest, stones) = separated_list1(space1, u64)(input)?;
+        let (rest, stones) = separated_list1(space1, context("stone number", u64))(input)?;
         let stones = stones.into_iter().map(Stone).collect();
         Ok((rest, Self(stones)))
     }
@@ -89,4 +110,17 @@ mod test {
         assert_eq!(num_stones(example)?, 55312);
         Ok(())
     }
+
+    #[test]
+    fn malformed_stones() {
+        let error = num_stones("125 17x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            indoc! {"
+                line 1, column 7: expected end of input
+                  |
+                1 | 125 17x
+                  |       ^"}
+        );
+    }
 }
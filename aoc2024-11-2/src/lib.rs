@@ -1,25 +1,47 @@
 use std::collections::VecDeque;
 
-use common::parse::{self, Parse};
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
 use ilog::IntLog;
-use nom::{character::streaming::space1, multi::separated_list1};
+use nom::{character::complete::space1, error::context, multi::separated_list1};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
 
 pub fn num_stones(input: &str, count: usize) -> Result<usize> {
-    let (_, stones) = Stones::parse(input).map_err(|e| Error::Parse(e.into()))?;
+    let stones = Solver::parse(input, Mode::Strict)?;
     Ok(stones.num_descendents_after(count))
 }
 
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 11;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    type Input = Stones;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Stones> {
+        Ok(parse::complete(input, mode, Stones::parse)?)
+    }
+
+    fn solve(stones: Stones) -> Result<usize> {
+        Ok(stones.num_descendents_after(75))
+    }
+}
+
 #[derive(Hash, Debug, Clone, PartialEq, Eq)]
 struct Stone(u64);
 
@@ -58,7 +80,7 @@ impl Stone {
 }
 
 #[derive(Debug)]
-struct Stones(VecDeque<Stone>);
+pub struct Stones(VecDeque<Stone>);
 
 impl Stones {
     fn num_descendents_after(self, n: usize) -> usize {
@@ -71,9 +93,9 @@ impl Stones {
 }
 
 impl Parse for Stones {
-    fn parse(input: &str) -> nom::IResult<&str, Self> {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
         use nom::character::complete::u64;
-        let (rest, stones) = separated_list1(space1, u64)(input)?;
+        let (rest, stones) = separated_list1(space1, context("stone number", u64))(input)?;
         let stones = stones.into_iter().map(Stone).collect();
         Ok((rest, Self(stones)))
     }
@@ -1,11 +1,9 @@
-use std::io::{self};
+use std::process::ExitCode;
 
-use aoc2024_11_2::{num_stones, Result};
+use aoc2024_11_2::Solver;
+use common::cli;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
     tracing_subscriber::fmt::init();
-    let stdin = io::read_to_string(io::stdin())?;
-    let answer = num_stones(&stdin, 75)?;
-    println!("Answer: {answer}");
-    Ok(())
+    cli::run::<Solver>()
 }
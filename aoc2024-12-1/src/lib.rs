@@ -1,43 +1,56 @@
 use std::{collections::HashMap, hash::Hash, ops::AddAssign};
 
-use common::parse::{self};
+use common::{
+    geom::{Direction, Point},
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error(transparent)]
+    #[error("{0}")]
     Parse(#[from] parse::Error),
 }
 
 pub fn fence_price(it: impl Iterator<Item = String>) -> Result<u64> {
-    let mut partition = Partition::default();
-    for (row, line) in it.enumerate() {
-        for (col, crop) in line.chars().enumerate() {
-            let vertex = Vertex {
-                x: col as u64,
-                y: row as u64,
-            };
-            partition.push_plot(vertex, crop);
+    Solver::solve(Grid::from_lines(it, |crop| crop)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 12;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Garden Groups";
+    type Input = Grid<char>;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, _mode: Mode) -> Result<Grid<char>> {
+        Ok(input.parse()?)
+    }
+
+    fn solve(garden: Grid<char>) -> Result<u64> {
+        let mut partition = Partition::default();
+        for (plot, crop) in garden.iter() {
+            partition.push_plot(plot, *crop);
         }
+        Ok(partition.total_price() as u64)
     }
-    Ok(partition.total_price() as u64)
 }
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-struct Vertex {
-    x: u64,
-    y: u64,
-}
+#[derive(Eq, Clone, Copy)]
+struct Edge(Point, Point);
 
-impl Vertex {
-    fn plot_edges(&self) -> [Edge; 4] {
-        let Vertex { x, y } = self;
-        let top_left = *self;
-        let top_right = Vertex { x: x + 1, y: *y };
-        let bottom_left = Vertex { x: *x, y: y + 1 };
-        let bottom_right = Vertex { x: x + 1, y: y + 1 };
+impl Edge {
+    fn around(top_left: Point) -> [Edge; 4] {
+        let top_right = top_left + Direction::E;
+        let bottom_left = top_left + Direction::S;
+        let bottom_right = bottom_left + Direction::E;
         [
             Edge(top_left, top_right),
             Edge(top_left, bottom_left),
@@ -47,9 +60,6 @@ impl Vertex {
     }
 }
 
-#[derive(Eq, Clone, Copy)]
-struct Edge(Vertex, Vertex);
-
 impl PartialEq for Edge {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0 && self.1 == other.1 || self.0 == other.1 && self.1 == other.0
@@ -59,18 +69,12 @@ impl PartialEq for Edge {
 impl Hash for Edge {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         let Edge(v1, v2) = self;
-        let Vertex { x: x1, y: y1 } = v1;
-        let Vertex { x: x2, y: y2 } = v2;
-        if x1 < x2 || x1 == x2 && y1 < y2 {
-            x1.hash(state);
-            y1.hash(state);
-            x2.hash(state);
-            y2.hash(state);
+        if v1 < v2 {
+            v1.hash(state);
+            v2.hash(state);
         } else {
-            x2.hash(state);
-            y2.hash(state);
-            x1.hash(state);
-            y1.hash(state);
+            v2.hash(state);
+            v1.hash(state);
         }
     }
 }
@@ -118,11 +122,10 @@ impl Partition {
         incident
     }
 
-    fn push_plot(&mut self, top_left_vertex: Vertex, crop: char) {
+    fn push_plot(&mut self, top_left_vertex: Point, crop: char) {
         let mut region = Region {
             area: 1,
-            edges: top_left_vertex
-                .plot_edges()
+            edges: Edge::around(top_left_vertex)
                 .iter()
                 .cloned()
                 .map(|edge| (edge, 1))
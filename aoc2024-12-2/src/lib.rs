@@ -0,0 +1,208 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::AddAssign,
+};
+
+use common::{
+    geom::{Direction, Point},
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+pub fn fence_price(it: impl Iterator<Item = String>) -> Result<u64> {
+    Solver::solve(Grid::from_lines(it, |crop| crop)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 12;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Garden Groups";
+    type Input = Grid<char>;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, _mode: Mode) -> Result<Grid<char>> {
+        Ok(input.parse()?)
+    }
+
+    fn solve(garden: Grid<char>) -> Result<u64> {
+        let mut partition = Partition::default();
+        for (plot, crop) in garden.iter() {
+            partition.push_plot(plot, *crop);
+        }
+        Ok(partition.total_price() as u64)
+    }
+}
+
+/// The piece of fence on one `side` of a plot. Unlike an undirected edge this
+/// knows which way it faces, so the fences of two plots touching at a corner
+/// are never mistaken for one straight side.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct Fence {
+    plot: Point,
+    side: Direction,
+}
+
+impl Fence {
+    fn around(plot: Point) -> [Fence; 4] {
+        Direction::ALL.map(|side| Fence { plot, side })
+    }
+
+    /// The same piece of fence seen from the neighbouring plot.
+    fn opposite(&self) -> Self {
+        Self {
+            plot: self.plot + self.side,
+            side: self.side.reverse(),
+        }
+    }
+
+    /// The adjacent piece of fence that would continue this one's side.
+    fn previous(&self) -> Self {
+        Self {
+            plot: self.plot + self.side.turn_left(),
+            side: self.side,
+        }
+    }
+}
+
+struct Region {
+    area: usize,
+    fences: HashSet<Fence>,
+}
+
+impl Region {
+    fn sides(&self) -> usize {
+        self.fences
+            .iter()
+            .filter(|fence| !self.fences.contains(&fence.previous()))
+            .count()
+    }
+
+    fn touches(&self, fence: &Fence) -> bool {
+        self.fences.contains(&fence.opposite())
+    }
+
+    fn price(&self) -> usize {
+        self.area * self.sides()
+    }
+}
+
+impl AddAssign for Region {
+    fn add_assign(&mut self, other: Self) {
+        self.area += other.area;
+        for fence in other.fences {
+            if !self.fences.remove(&fence.opposite()) {
+                self.fences.insert(fence);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Partition(HashMap<char, Vec<Region>>);
+
+impl Partition {
+    fn pop_incident_regions(&mut self, region: &Region, crop: char) -> Vec<Region> {
+        let Some(candidate_regions) = self.0.remove(&crop) else {
+            return Vec::new();
+        };
+        let (incident, disjoint): (Vec<_>, Vec<_>) = candidate_regions
+            .into_iter()
+            .partition(|candidate| region.fences.iter().any(|fence| candidate.touches(fence)));
+        self.0.insert(crop, disjoint);
+        incident
+    }
+
+    fn push_plot(&mut self, plot: Point, crop: char) {
+        let mut region = Region {
+            area: 1,
+            fences: Fence::around(plot).into_iter().collect(),
+        };
+        for incident_region in self.pop_incident_regions(&region, crop) {
+            region += incident_region;
+        }
+        self.0.entry(crop).or_default().push(region);
+    }
+
+    fn total_price(&self) -> usize {
+        self.0
+            .values()
+            .map(|regions| regions.iter().map(Region::price).sum::<usize>())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn tiny_full_example() -> Result<()> {
+        let example = indoc! {"
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        "};
+        assert_eq!(fence_price(example.lines().map(String::from))?, 80);
+        Ok(())
+    }
+
+    #[test]
+    fn enclosed_regions() -> Result<()> {
+        let example = indoc! {"
+            OOOOO
+            OXOXO
+            OOOOO
+            OXOXO
+            OOOOO
+        "};
+        assert_eq!(fence_price(example.lines().map(String::from))?, 436);
+        Ok(())
+    }
+
+    #[test]
+    fn diagonal_touching() -> Result<()> {
+        let example = indoc! {"
+            AAAAAA
+            AAABBA
+            AAABBA
+            ABBAAA
+            ABBAAA
+            AAAAAA
+        "};
+        assert_eq!(fence_price(example.lines().map(String::from))?, 368);
+        Ok(())
+    }
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            RRRRIICCFF
+            RRRRIICCCF
+            VVRRRCCFFF
+            VVRCCCJFFF
+            VVVVCJJCFE
+            VVIVCCJJEE
+            VVIIICJJEE
+            MIIIIIJJEE
+            MIIISIJEEE
+            MMMISSJEEE
+        "};
+        assert_eq!(fence_price(example.lines().map(String::from))?, 1206);
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+use std::process::ExitCode;
+
+use aoc2024_13_1::Solver;
+use common::cli;
+
+fn main() -> ExitCode {
+    tracing_subscriber::fmt::init();
+    cli::run::<Solver>()
+}
@@ -0,0 +1,243 @@
+use common::{
+    geom::Point,
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, multispace1, u32, u64},
+    combinator::{map, map_res},
+    error::context,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated, tuple},
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+pub fn fewest_tokens(input: &str) -> Result<u64> {
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 13;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Claw Contraption";
+    type Input = Arcade;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Arcade> {
+        Ok(parse::complete(input, mode, Arcade::parse)?)
+    }
+
+    fn solve(arcade: Arcade) -> Result<u64> {
+        Ok(arcade
+            .0
+            .into_iter()
+            .map(Machine::recalibrated)
+            .filter_map(|machine| machine.cheapest_win(None))
+            .sum())
+    }
+}
+
+pub struct Arcade(Vec<Machine>);
+
+impl Parse for Arcade {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let (rest, machines) = separated_list1(multispace1, Machine::parse)(input)?;
+        Ok((rest, Self(machines)))
+    }
+}
+
+const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
+struct Machine {
+    a: Point,
+    b: Point,
+    prize: Point,
+}
+
+fn cross(u: &Point, v: &Point) -> i64 {
+    u.x * v.y - u.y * v.x
+}
+
+/// `(g, x, y)` with `k * x + m * y == g == gcd(k, m)`.
+fn extended_gcd(k: i64, m: i64) -> (i64, i64, i64) {
+    if m == 0 {
+        (k, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(m, k % m);
+        (g, y, x - (k / m) * y)
+    }
+}
+
+/// Cheapest non-negative `(a, b)`, neither above `limit`, with
+/// `a * k + b * m == n`, where `k`, `m` and `n` are non-negative and `k`, `m`
+/// not both zero.
+fn cheapest_along_line(k: i64, m: i64, n: i64, limit: Option<i64>) -> Option<(i64, i64)> {
+    let within = |&(a, b): &(i64, i64)| limit.is_none_or(|limit| a <= limit && b <= limit);
+    if k == 0 {
+        return (n % m == 0).then(|| (0, n / m)).filter(within);
+    }
+    if m == 0 {
+        return (n % k == 0).then(|| (n / k, 0)).filter(within);
+    }
+    let (g, x, y) = extended_gcd(k, m);
+    if n % g != 0 {
+        return None;
+    }
+    // Every solution is (a0 + t * da, b0 - t * db); the cost is linear in t,
+    // so the cheapest lies at one end of the range keeping both non-negative
+    // and within the limit.
+    let (a0, b0) = (x * (n / g), y * (n / g));
+    let (da, db) = (m / g, k / g);
+    let mut t_min = -(a0.div_euclid(da));
+    let mut t_max = b0.div_euclid(db);
+    if let Some(limit) = limit {
+        t_min = t_min.max(-((limit - b0).div_euclid(db)));
+        t_max = t_max.min((limit - a0).div_euclid(da));
+    }
+    if t_min > t_max {
+        return None;
+    }
+    let t = if 3 * da >= db { t_min } else { t_max };
+    Some((a0 + t * da, b0 - t * db))
+}
+
+impl Machine {
+    fn recalibrated(self) -> Self {
+        Self {
+            prize: self.prize + Point::new(PRIZE_OFFSET, PRIZE_OFFSET),
+            ..self
+        }
+    }
+
+    /// Button presses `(a, b)`, neither above `limit`, reaching the prize, the
+    /// cheapest if there are several.
+    fn presses(&self, limit: Option<i64>) -> Option<(i64, i64)> {
+        let Self { a, b, prize } = self;
+        if *prize == Point::ORIGIN {
+            return Some((0, 0));
+        }
+        match cross(a, b) {
+            0 => {
+                // Collinear buttons: only a prize on the same line is
+                // reachable, leaving a one-dimensional problem along it.
+                if cross(a, prize) != 0 || cross(b, prize) != 0 {
+                    return None;
+                }
+                let direction = [a, b].into_iter().find(|v| **v != Point::ORIGIN)?;
+                let along = |v: &Point| if direction.x != 0 { v.x } else { v.y };
+                cheapest_along_line(along(a), along(b), along(prize), limit)
+            }
+            det => {
+                let a_presses = cross(prize, b);
+                let b_presses = cross(a, prize);
+                if a_presses % det != 0 || b_presses % det != 0 {
+                    return None;
+                }
+                let presses = (a_presses / det, b_presses / det);
+                let within = limit.is_none_or(|limit| presses.0 <= limit && presses.1 <= limit);
+                (presses.0 >= 0 && presses.1 >= 0 && within).then_some(presses)
+            }
+        }
+    }
+
+    fn cheapest_win(&self, limit: Option<i64>) -> Option<u64> {
+        let (a, b) = self.presses(limit)?;
+        Some((3 * a + b) as u64)
+    }
+}
+
+impl Parse for Machine {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let button = |label: &'static str| {
+            preceded(
+                tag(label),
+                separated_pair(
+                    preceded(tag("X+"), map(u32, i64::from)),
+                    tag(", "),
+                    preceded(tag("Y+"), map(u32, i64::from)),
+                ),
+            )
+        };
+        let coordinate = || map_res(u64, i64::try_from);
+        let prize = preceded(
+            tag("Prize: "),
+            separated_pair(
+                preceded(tag("X="), coordinate()),
+                tag(", "),
+                preceded(tag("Y="), coordinate()),
+            ),
+        );
+        let (rest, (a, b, prize)) = tuple((
+            context(
+                "`Button A: X+<n>, Y+<n>`",
+                terminated(button("Button A: "), line_ending),
+            ),
+            context(
+                "`Button B: X+<n>, Y+<n>`",
+                terminated(button("Button B: "), line_ending),
+            ),
+            context("`Prize: X=<n>, Y=<n>`", prize),
+        ))(input)?;
+        let point = |(x, y)| Point::new(x, y);
+        Ok((
+            rest,
+            Self {
+                a: point(a),
+                b: point(b),
+                prize: point(prize),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            Button A: X+94, Y+34
+            Button B: X+22, Y+67
+            Prize: X=8400, Y=5400
+
+            Button A: X+26, Y+66
+            Button B: X+67, Y+21
+            Prize: X=12748, Y=12176
+
+            Button A: X+17, Y+86
+            Button B: X+84, Y+37
+            Prize: X=7870, Y=6450
+
+            Button A: X+69, Y+23
+            Button B: X+27, Y+71
+            Prize: X=18641, Y=10279
+        "};
+        assert_eq!(fewest_tokens(example)?, 875318608908);
+        Ok(())
+    }
+
+    #[test]
+    fn collinear_buttons() -> Result<()> {
+        let example = indoc! {"
+            Button A: X+4, Y+4
+            Button B: X+1, Y+1
+            Prize: X=0, Y=0
+        "};
+        assert_eq!(fewest_tokens(example)?, 7_500_000_000_000);
+        Ok(())
+    }
+}
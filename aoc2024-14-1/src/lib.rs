@@ -0,0 +1,123 @@
+use common::{
+    geom::Point,
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, i64, space1},
+    error::context,
+    sequence::{preceded, separated_pair},
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+/// The size of the real bathroom; the puzzle example uses 11 by 7.
+pub const BATHROOM: Point = Point::new(101, 103);
+
+const SECONDS: i64 = 100;
+
+pub fn safety_factor(input: &str, size: Point) -> Result<usize> {
+    let robots = Solver::parse(input, Mode::Strict)?;
+    Ok(robots.safety_factor(size, SECONDS))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 14;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Restroom Redoubt";
+    type Input = Robots;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Robots> {
+        Ok(Robots(parse::lines(input.lines(), mode, Robot::parse)?))
+    }
+
+    fn solve(robots: Robots) -> Result<usize> {
+        Ok(robots.safety_factor(BATHROOM, SECONDS))
+    }
+}
+
+pub struct Robots(Vec<Robot>);
+
+impl Robots {
+    fn safety_factor(&self, size: Point, seconds: i64) -> usize {
+        let middle = Point::new(size.x / 2, size.y / 2);
+        let mut quadrants = [0; 4];
+        for robot in &self.0 {
+            let Point { x, y } = robot.position_after(seconds, size);
+            if (size.x % 2 == 1 && x == middle.x) || (size.y % 2 == 1 && y == middle.y) {
+                continue;
+            }
+            quadrants[usize::from(x >= middle.x) + 2 * usize::from(y >= middle.y)] += 1;
+        }
+        quadrants.iter().product()
+    }
+}
+
+struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+impl Robot {
+    fn position_after(&self, seconds: i64, size: Point) -> Point {
+        let Point { x, y } = self.position + self.velocity * seconds;
+        Point::new(x.rem_euclid(size.x), y.rem_euclid(size.y))
+    }
+}
+
+impl Parse for Robot {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let point = || separated_pair(i64, char(','), i64);
+        let (rest, (position, velocity)) = separated_pair(
+            context("`p=<x>,<y>` position", preceded(tag("p="), point())),
+            space1,
+            context("`v=<dx>,<dy>` velocity", preceded(tag("v="), point())),
+        )(input)?;
+        let point = |(x, y)| Point::new(x, y);
+        Ok((
+            rest,
+            Self {
+                position: point(position),
+                velocity: point(velocity),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            p=0,4 v=3,-3
+            p=6,3 v=-1,-3
+            p=10,3 v=-1,2
+            p=2,0 v=2,-1
+            p=0,0 v=1,3
+            p=3,0 v=-2,-2
+            p=7,6 v=-1,-3
+            p=3,0 v=-1,-2
+            p=9,3 v=2,3
+            p=7,3 v=-1,2
+            p=2,4 v=2,-3
+            p=9,5 v=-3,-3
+        "};
+        assert_eq!(safety_factor(example, Point::new(11, 7))?, 12);
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use common::{
+    geom::Point,
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, i64, space1},
+    error::context,
+    sequence::{preceded, separated_pair},
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("the robots never settle into an image")]
+    NoImage,
+}
+
+/// The size of the real bathroom; the puzzle example uses 11 by 7.
+pub const BATHROOM: Point = Point::new(101, 103);
+
+pub fn seconds_until_image(input: &str, size: Point) -> Result<i64> {
+    let robots = Solver::parse(input, Mode::Strict)?;
+    robots.seconds_until_image(size).ok_or(Error::NoImage)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 14;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Restroom Redoubt";
+    type Input = Robots;
+    type Answer = i64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Robots> {
+        Ok(Robots(parse::lines(input.lines(), mode, Robot::parse)?))
+    }
+
+    fn solve(robots: Robots) -> Result<i64> {
+        robots.seconds_until_image(BATHROOM).ok_or(Error::NoImage)
+    }
+}
+
+/// `n²` times the variance of `values`, which is all that's needed to compare
+/// spreads of the same number of values.
+fn spread(values: impl Iterator<Item = i64>) -> i64 {
+    let (n, sum, sum_of_squares) = values.fold((0, 0, 0), |(n, sum, squares), v| {
+        (n + 1, sum + v, squares + v * v)
+    });
+    n * sum_of_squares - sum * sum
+}
+
+pub struct Robots(Vec<Robot>);
+
+impl Robots {
+    /// The robots draw an image when they bunch up. Columns repeat every
+    /// `size.x` seconds and rows every `size.y`, so the tightest second for
+    /// each axis is found independently and the two are combined.
+    fn seconds_until_image(&self, size: Point) -> Option<i64> {
+        let tightest = |period: i64, axis: fn(Point) -> i64| {
+            (0..period).min_by_key(|&seconds| {
+                spread(
+                    self.0
+                        .iter()
+                        .map(|robot| axis(robot.position_after(seconds, size))),
+                )
+            })
+        };
+        let x = tightest(size.x, |p| p.x)?;
+        let y = tightest(size.y, |p| p.y)?;
+        (0..size.y)
+            .map(|k| x + k * size.x)
+            .find(|seconds| seconds % size.y == y)
+    }
+}
+
+struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+impl Robot {
+    fn position_after(&self, seconds: i64, size: Point) -> Point {
+        let Point { x, y } = self.position + self.velocity * seconds;
+        Point::new(x.rem_euclid(size.x), y.rem_euclid(size.y))
+    }
+}
+
+impl Parse for Robot {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let point = || separated_pair(i64, char(','), i64);
+        let (rest, (position, velocity)) = separated_pair(
+            context("`p=<x>,<y>` position", preceded(tag("p="), point())),
+            space1,
+            context("`v=<dx>,<dy>` velocity", preceded(tag("v="), point())),
+        )(input)?;
+        let point = |(x, y)| Point::new(x, y);
+        Ok((
+            rest,
+            Self {
+                position: point(position),
+                velocity: point(velocity),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn converging_robots() -> Result<()> {
+        // Robots placed so that after 30 seconds they all sit in a 3x3 block.
+        let size = Point::new(11, 7);
+        let seconds = 30;
+        let input: String = (0..20)
+            .map(|i| {
+                let target = Point::new(4 + i % 3, 2 + (i / 3) % 3);
+                let velocity = Point::new(i % 5 - 2, (i * 3) % 7 - 3);
+                let Point { x, y } = target - velocity * seconds;
+                format!(
+                    "p={},{} v={},{}\n",
+                    x.rem_euclid(size.x),
+                    y.rem_euclid(size.y),
+                    velocity.x,
+                    velocity.y
+                )
+            })
+            .collect();
+        assert_eq!(seconds_until_image(&input, size)?, seconds);
+        Ok(())
+    }
+}
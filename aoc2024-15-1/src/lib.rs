@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use common::{
+    geom::{Direction, Point},
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
+use nom::{character::complete::anychar, combinator::map_opt, error::context, multi::many1};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("missing robot ('@')")]
+    MissingRobot,
+}
+
+pub fn gps_sum(it: impl Iterator<Item = String>, mode: Mode) -> Result<i64> {
+    Solver::solve(Warehouse::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 15;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Warehouse Woes";
+    type Input = Warehouse;
+    type Answer = i64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Warehouse> {
+        Warehouse::parse(input.lines(), mode)
+    }
+
+    fn solve(mut warehouse: Warehouse) -> Result<i64> {
+        for direction in std::mem::take(&mut warehouse.moves) {
+            warehouse.step(direction);
+        }
+        Ok(warehouse.gps_sum())
+    }
+}
+
+pub struct Warehouse {
+    map: Grid<char>,
+    robot: Point,
+    moves: Vec<Direction>,
+}
+
+fn moves(input: &str) -> parse::IResult<'_, Vec<Direction>> {
+    let direction = map_opt(anychar, |ch| match ch {
+        '^' => Some(Direction::N),
+        '>' => Some(Direction::E),
+        'v' => Some(Direction::S),
+        '<' => Some(Direction::W),
+        _ => None,
+    });
+    many1(context("move (`^`, `>`, `v` or `<`)", direction))(input)
+}
+
+impl Warehouse {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> Result<Self> {
+        let mut lines = lines.enumerate().map(|(idx, line)| (idx + 1, line));
+        let rows = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.as_ref().is_empty())
+            .map(|(_, line)| line);
+        let mut map = Grid::from_lines(rows, |ch| ch)?;
+        let robot = map.find(&'@').ok_or(Error::MissingRobot)?;
+        map[robot] = '.';
+        let moves = parse::numbered_lines(lines, mode, moves)?.concat();
+        Ok(Self { map, robot, moves })
+    }
+
+    /// Moves the robot, pushing any boxes in the way, unless something would
+    /// hit a wall, in which case nothing moves at all.
+    fn step(&mut self, direction: Direction) {
+        let mut pushed = Vec::new();
+        let mut seen = HashSet::new();
+        let mut frontier = vec![self.robot + direction];
+        while let Some(position) = frontier.pop() {
+            if !seen.insert(position) {
+                continue;
+            }
+            match self.map.get(&position) {
+                None | Some('#') => return,
+                Some('.') => {}
+                Some(_) => {
+                    pushed.push(position);
+                    frontier.push(position + direction);
+                }
+            }
+        }
+        let boxes: Vec<_> = pushed.iter().map(|&position| self.map[position]).collect();
+        for &position in &pushed {
+            self.map[position] = '.';
+        }
+        for (position, cell) in pushed.into_iter().zip(boxes) {
+            self.map[position + direction] = cell;
+        }
+        self.robot += direction;
+    }
+
+    fn gps_sum(&self) -> i64 {
+        self.map
+            .positions_of(&'O')
+            .map(|Point { x, y }| 100 * y + x)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn small_example() -> Result<()> {
+        let example = indoc! {"
+            ########
+            #..O.O.#
+            ##@.O..#
+            #...O..#
+            #.#.O..#
+            #...O..#
+            #......#
+            ########
+
+            <^^>>>vv<v>>v<<
+        "};
+        assert_eq!(
+            gps_sum(example.lines().map(String::from), Mode::Strict)?,
+            2028
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            ##########
+            #..O..O.O#
+            #......O.#
+            #.OO..O.O#
+            #..O@..O.#
+            #O#..O...#
+            #O..O..O.#
+            #.OO.O.OO#
+            #....O...#
+            ##########
+
+            <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+            vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+            ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+            <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+            ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+            ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+            >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+            <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+            ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+            v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+        "};
+        assert_eq!(
+            gps_sum(example.lines().map(String::from), Mode::Strict)?,
+            10092
+        );
+        Ok(())
+    }
+}
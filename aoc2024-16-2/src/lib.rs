@@ -0,0 +1,230 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use common::{
+    geom::{Direction, Point},
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("missing start tile ('S')")]
+    MissingStart,
+    #[error("missing end tile ('E')")]
+    MissingEnd,
+    #[error("no path from start to end")]
+    NoPath,
+}
+
+pub fn best_seats(it: impl Iterator<Item = String>) -> Result<usize> {
+    Solver::solve(Maze::parse(it)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 16;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Reindeer Maze";
+    type Input = Maze;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, _mode: Mode) -> Result<Maze> {
+        Maze::parse(input.lines())
+    }
+
+    fn solve(maze: Maze) -> Result<usize> {
+        let costs = maze.costs();
+        let finishes = Direction::ALL.into_iter().filter_map(|facing| {
+            let reindeer = Reindeer {
+                position: maze.end,
+                facing,
+            };
+            costs.get(&reindeer).map(|&cost| (reindeer, cost))
+        });
+        let lowest = finishes
+            .clone()
+            .map(|(_, cost)| cost)
+            .min()
+            .ok_or(Error::NoPath)?;
+
+        // Walk back from every optimal finish through states whose cost is
+        // exactly one action cheaper, which are the ones on optimal paths.
+        let mut stack: Vec<_> = finishes.filter(|&(_, cost)| cost == lowest).collect();
+        let mut seen: HashSet<_> = stack.iter().map(|&(reindeer, _)| reindeer).collect();
+        while let Some((reindeer, cost)) = stack.pop() {
+            for (previous, step) in reindeer.reversed_moves() {
+                let Some(cost) = cost.checked_sub(step) else {
+                    continue;
+                };
+                if costs.get(&previous) == Some(&cost) && seen.insert(previous) {
+                    stack.push((previous, cost));
+                }
+            }
+        }
+
+        let tiles: HashSet<_> = seen.into_iter().map(|reindeer| reindeer.position).collect();
+        Ok(tiles.len())
+    }
+}
+
+const STEP: u64 = 1;
+const TURN: u64 = 1000;
+
+pub struct Maze {
+    map: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Reindeer {
+    position: Point,
+    facing: Direction,
+}
+
+impl Reindeer {
+    /// States reachable with a single action, and what that action costs.
+    fn moves(self) -> [(Self, u64); 3] {
+        [
+            (
+                Self {
+                    position: self.position + self.facing,
+                    ..self
+                },
+                STEP,
+            ),
+            (
+                Self {
+                    facing: self.facing.turn_left(),
+                    ..self
+                },
+                TURN,
+            ),
+            (
+                Self {
+                    facing: self.facing.turn_right(),
+                    ..self
+                },
+                TURN,
+            ),
+        ]
+    }
+
+    /// States from which a single action leads here, and what that action
+    /// costs.
+    fn reversed_moves(self) -> [(Self, u64); 3] {
+        let [(_, step), left, right] = self.moves();
+        [
+            (
+                Self {
+                    position: self.position + self.facing.reverse(),
+                    ..self
+                },
+                step,
+            ),
+            left,
+            right,
+        ]
+    }
+}
+
+impl Maze {
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>) -> Result<Self> {
+        let map = Grid::from_lines(rows, |ch| ch)?;
+        let start = map.find(&'S').ok_or(Error::MissingStart)?;
+        let end = map.find(&'E').ok_or(Error::MissingEnd)?;
+        Ok(Self { map, start, end })
+    }
+
+    /// Dijkstra from the start tile facing east, giving the lowest cost of
+    /// every reachable state.
+    fn costs(&self) -> HashMap<Reindeer, u64> {
+        let start = Reindeer {
+            position: self.start,
+            facing: Direction::E,
+        };
+        let mut costs = HashMap::from([(start, 0)]);
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+        while let Some(Reverse((cost, reindeer))) = queue.pop() {
+            if costs.get(&reindeer).is_some_and(|&best| best < cost) {
+                continue;
+            }
+            for (next, step) in reindeer.moves() {
+                if self.map.get(&next.position).is_none_or(|&tile| tile == '#') {
+                    continue;
+                }
+                let cost = cost + step;
+                if costs.get(&next).is_none_or(|&best| cost < best) {
+                    costs.insert(next, cost);
+                    queue.push(Reverse((cost, next)));
+                }
+            }
+        }
+        costs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            ###############
+            #.......#....E#
+            #.#.###.#.###.#
+            #.....#.#...#.#
+            #.###.#####.#.#
+            #.#.#.......#.#
+            #.#.#####.###.#
+            #...........#.#
+            ###.#.#####.#.#
+            #...#.....#.#.#
+            #.#.#.###.#.#.#
+            #.....#...#.#.#
+            #.###.#.#.#.#.#
+            #S..#.....#...#
+            ###############
+        "};
+        assert_eq!(best_seats(example.lines().map(String::from))?, 45);
+        Ok(())
+    }
+
+    #[test]
+    fn second_example() -> Result<()> {
+        let example = indoc! {"
+            #################
+            #...#...#...#..E#
+            #.#.#.#.#.#.#.#.#
+            #.#.#.#...#...#.#
+            #.#.#.#.###.#.#.#
+            #...#.#.#.....#.#
+            #.#.#.#.#.#####.#
+            #.#...#.#.#.....#
+            #.#.#####.#.###.#
+            #.#.#.......#...#
+            #.#.###.#####.###
+            #.#.#...#.....#.#
+            #.#.#.#####.###.#
+            #.#.#.........#.#
+            #.#.#.#########.#
+            #S#.............#
+            #################
+        "};
+        assert_eq!(best_seats(example.lines().map(String::from))?, 64);
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, line_ending, multispace1, u64, u8},
+    combinator::verify,
+    error::context,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("combo operand 7 is reserved")]
+    ReservedOperand,
+    #[error("program didn't halt within {BUDGET} instructions")]
+    NoHalt,
+}
+
+pub fn output(input: &str) -> Result<String> {
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 17;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Chronospatial Computer";
+    type Input = Computer;
+    type Answer = String;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Computer> {
+        Ok(parse::complete(input, mode, Computer::parse)?)
+    }
+
+    fn solve(computer: Computer) -> Result<String> {
+        let output = computer.run()?;
+        Ok(output
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+}
+
+/// Instructions a program may run before it's taken not to halt.
+const BUDGET: usize = 1 << 20;
+
+pub struct Computer {
+    registers: [u64; 3],
+    program: Vec<u8>,
+}
+
+impl Computer {
+    fn run(&self) -> Result<Vec<u8>> {
+        let [mut a, mut b, mut c] = self.registers;
+        let mut output = Vec::new();
+        let mut ip = 0;
+        for _ in 0..BUDGET {
+            let (Some(&opcode), Some(&operand)) = (self.program.get(ip), self.program.get(ip + 1))
+            else {
+                return Ok(output);
+            };
+            let literal = u64::from(operand);
+            let combo = || match operand {
+                0..=3 => Ok(literal),
+                4 => Ok(a),
+                5 => Ok(b),
+                6 => Ok(c),
+                _ => Err(Error::ReservedOperand),
+            };
+            let divide = |shift: u64| {
+                u32::try_from(shift)
+                    .ok()
+                    .and_then(|shift| a.checked_shr(shift))
+                    .unwrap_or(0)
+            };
+            ip += 2;
+            match opcode {
+                0 => a = divide(combo()?),
+                1 => b ^= literal,
+                2 => b = combo()? % 8,
+                3 if a != 0 => ip = operand.into(),
+                3 => {}
+                4 => b ^= c,
+                5 => output.push((combo()? % 8) as u8),
+                6 => b = divide(combo()?),
+                _ => c = divide(combo()?),
+            }
+        }
+        Err(Error::NoHalt)
+    }
+}
+
+impl Parse for Computer {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let register = |name: &'static str| {
+            context(
+                "`Register <name>: <n>`",
+                delimited(
+                    tuple((tag("Register "), tag(name), tag(": "))),
+                    u64,
+                    line_ending,
+                ),
+            )
+        };
+        let (rest, (a, b, c, program)) = tuple((
+            register("A"),
+            register("B"),
+            register("C"),
+            preceded(
+                multispace1,
+                preceded(
+                    context("`Program: `", tag("Program: ")),
+                    separated_list1(char(','), context("3-bit number", verify(u8, |&n| n < 8))),
+                ),
+            ),
+        ))(input)?;
+        Ok((
+            rest,
+            Self {
+                registers: [a, b, c],
+                program,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            Register A: 729
+            Register B: 0
+            Register C: 0
+
+            Program: 0,1,5,4,3,0
+        "};
+        assert_eq!(output(example)?, "4,6,3,5,6,3,5,2,1,0");
+        Ok(())
+    }
+
+    #[test]
+    fn endless_loop() {
+        let example = indoc! {"
+            Register A: 1
+            Register B: 0
+            Register C: 0
+
+            Program: 3,0
+        "};
+        assert!(matches!(output(example), Err(Error::NoHalt)));
+    }
+
+    #[test]
+    fn small_examples() -> Result<()> {
+        let computer = |registers, program: &[u8]| Computer {
+            registers,
+            program: program.to_vec(),
+        };
+        assert_eq!(computer([10, 0, 0], &[5, 0, 5, 1, 5, 4]).run()?, [0, 1, 2]);
+        assert_eq!(
+            computer([2024, 0, 0], &[0, 1, 5, 4, 3, 0]).run()?,
+            [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]
+        );
+        assert_eq!(computer([0, 0, 9], &[2, 6, 5, 5]).run()?, [1]);
+        assert_eq!(computer([0, 29, 0], &[1, 7, 5, 5]).run()?, [2]);
+        assert_eq!(computer([0, 2024, 43690], &[4, 0, 5, 5]).run()?, [2]);
+        Ok(())
+    }
+}
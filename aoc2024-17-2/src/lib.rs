@@ -0,0 +1,227 @@
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, line_ending, multispace1, u64, u8},
+    combinator::verify,
+    error::context,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("combo operand 7 is reserved")]
+    ReservedOperand,
+    #[error("program didn't halt within {BUDGET} instructions")]
+    NoHalt,
+    #[error("no value of register A makes the program output itself")]
+    NoQuine,
+}
+
+pub fn quine_register(input: &str) -> Result<u64> {
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 17;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Chronospatial Computer";
+    type Input = Computer;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Computer> {
+        Ok(parse::complete(input, mode, Computer::parse)?)
+    }
+
+    fn solve(computer: Computer) -> Result<u64> {
+        if !computer.shifts_octal_digits() {
+            return Err(Error::NoQuine);
+        }
+        computer
+            .search(0, computer.program.len())?
+            .ok_or(Error::NoQuine)
+    }
+}
+
+/// Instructions a program may run before it's taken not to halt.
+const BUDGET: usize = 1 << 20;
+
+pub struct Computer {
+    registers: [u64; 3],
+    program: Vec<u8>,
+}
+
+impl Computer {
+    /// Whether the program has the shape [`Computer::search`] relies on: a
+    /// single loop, jumping back to the start at the end, that shifts three
+    /// bits off A once and outputs once per pass.
+    fn shifts_octal_digits(&self) -> bool {
+        let instructions: Vec<_> = self.program.chunks(2).collect();
+        let count = |opcode| {
+            instructions
+                .iter()
+                .filter(|instruction| instruction[0] == opcode)
+                .count()
+        };
+        instructions
+            .iter()
+            .filter(|&&instruction| instruction == [0, 3])
+            .count()
+            == 1
+            && count(0) == 1
+            && count(5) == 1
+            && count(3) == 1
+            && instructions.last() == Some(&&[3, 0][..])
+    }
+
+    /// Smallest positive register A, extending `prefix` by one octal digit at
+    /// a time, for which the program outputs `program[from..]`.
+    ///
+    /// This relies on the program being a loop that outputs once per pass,
+    /// with the output depending only on A, and then shifts three bits off A.
+    /// The last output then depends only on the highest octal digit of A,
+    /// the one before on the two highest, and so on.
+    fn search(&self, prefix: u64, from: usize) -> Result<Option<u64>> {
+        if from == 0 {
+            return Ok(Some(prefix));
+        }
+        let Some(prefix) = prefix.checked_mul(8) else {
+            return Ok(None);
+        };
+        for digit in 0..8 {
+            let a = prefix + digit;
+            if a == 0 || self.run(a)? != self.program[from - 1..] {
+                continue;
+            }
+            if let Some(found) = self.search(a, from - 1)? {
+                return Ok(Some(found));
+            }
+        }
+        Ok(None)
+    }
+
+    fn run(&self, mut a: u64) -> Result<Vec<u8>> {
+        let [_, mut b, mut c] = self.registers;
+        let mut output = Vec::new();
+        let mut ip = 0;
+        for _ in 0..BUDGET {
+            let (Some(&opcode), Some(&operand)) = (self.program.get(ip), self.program.get(ip + 1))
+            else {
+                return Ok(output);
+            };
+            let literal = u64::from(operand);
+            let combo = || match operand {
+                0..=3 => Ok(literal),
+                4 => Ok(a),
+                5 => Ok(b),
+                6 => Ok(c),
+                _ => Err(Error::ReservedOperand),
+            };
+            let divide = |shift: u64| {
+                u32::try_from(shift)
+                    .ok()
+                    .and_then(|shift| a.checked_shr(shift))
+                    .unwrap_or(0)
+            };
+            ip += 2;
+            match opcode {
+                0 => a = divide(combo()?),
+                1 => b ^= literal,
+                2 => b = combo()? % 8,
+                3 if a != 0 => ip = operand.into(),
+                3 => {}
+                4 => b ^= c,
+                5 => output.push((combo()? % 8) as u8),
+                6 => b = divide(combo()?),
+                _ => c = divide(combo()?),
+            }
+        }
+        Err(Error::NoHalt)
+    }
+}
+
+impl Parse for Computer {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let register = |name: &'static str| {
+            context(
+                "`Register <name>: <n>`",
+                delimited(
+                    tuple((tag("Register "), tag(name), tag(": "))),
+                    u64,
+                    line_ending,
+                ),
+            )
+        };
+        let (rest, (a, b, c, program)) = tuple((
+            register("A"),
+            register("B"),
+            register("C"),
+            preceded(
+                multispace1,
+                preceded(
+                    context("`Program: `", tag("Program: ")),
+                    separated_list1(char(','), context("3-bit number", verify(u8, |&n| n < 8))),
+                ),
+            ),
+        ))(input)?;
+        Ok((
+            rest,
+            Self {
+                registers: [a, b, c],
+                program,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            Register A: 2024
+            Register B: 0
+            Register C: 0
+
+            Program: 0,3,5,4,3,0
+        "};
+        assert_eq!(quine_register(example)?, 117440);
+        Ok(())
+    }
+
+    #[test]
+    fn not_shifting_octal_digits() {
+        let example = indoc! {"
+            Register A: 0
+            Register B: 0
+            Register C: 0
+
+            Program: 5,4,3,0
+        "};
+        assert!(matches!(quine_register(example), Err(Error::NoQuine)));
+    }
+
+    #[test]
+    fn outputs_itself() -> Result<()> {
+        let computer = Computer {
+            registers: [0, 0, 0],
+            program: vec![0, 3, 5, 4, 3, 0],
+        };
+        assert_eq!(computer.run(117440)?, computer.program);
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+
+use common::{
+    geom::Point,
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
+use nom::{
+    character::complete::{char, i64},
+    error::context,
+    sequence::separated_pair,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("byte at {},{} falls outside the memory space", .0.x, .0.y)]
+    OutOfBounds(Point),
+    #[error("no path to the exit")]
+    NoPath,
+}
+
+/// The size of the real memory space; the puzzle example uses 7 by 7.
+pub const MEMORY: Point = Point::new(71, 71);
+
+const FALLEN: usize = 1024;
+
+pub fn min_steps(input: &str, size: Point, fallen: usize) -> Result<usize> {
+    let bytes = Solver::parse(input, Mode::Strict)?;
+    bytes.min_steps(size, fallen)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 18;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "RAM Run";
+    type Input = Bytes;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Bytes> {
+        Ok(Bytes(parse::lines(input.lines(), mode, byte)?))
+    }
+
+    fn solve(bytes: Bytes) -> Result<usize> {
+        bytes.min_steps(MEMORY, FALLEN)
+    }
+}
+
+fn byte(input: &str) -> parse::IResult<'_, Point> {
+    let (rest, (x, y)) = context("`<x>,<y>` position", separated_pair(i64, char(','), i64))(input)?;
+    Ok((rest, Point::new(x, y)))
+}
+
+pub struct Bytes(Vec<Point>);
+
+impl Bytes {
+    fn corrupted(&self, size: Point, fallen: usize) -> Result<Grid<bool>> {
+        let (width, height) = size.as_indices().unwrap_or_default();
+        let mut memory = Grid::new(width, height, false);
+        for &byte in self.0.iter().take(fallen) {
+            *memory.get_mut(&byte).ok_or(Error::OutOfBounds(byte))? = true;
+        }
+        Ok(memory)
+    }
+
+    fn min_steps(&self, size: Point, fallen: usize) -> Result<usize> {
+        let memory = self.corrupted(size, fallen)?;
+        let exit = size - Point::new(1, 1);
+        let mut steps = Grid::new(memory.width(), memory.height(), None);
+        let mut queue = VecDeque::new();
+        if memory.get(&Point::ORIGIN) == Some(&false) {
+            steps[Point::ORIGIN] = Some(0);
+            queue.push_back(Point::ORIGIN);
+        }
+        while let Some(position) = queue.pop_front() {
+            let taken = steps[position].unwrap_or_default();
+            if position == exit {
+                return Ok(taken);
+            }
+            for neighbour in memory.neighbours4(position) {
+                if !memory[neighbour] && steps[neighbour].is_none() {
+                    steps[neighbour] = Some(taken + 1);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        Err(Error::NoPath)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            5,4
+            4,2
+            4,5
+            3,0
+            2,1
+            6,3
+            2,4
+            1,5
+            0,6
+            3,3
+            2,6
+            5,1
+            1,2
+            5,5
+            2,5
+            6,5
+            1,4
+            0,4
+            6,4
+            1,1
+            6,1
+            1,0
+            0,5
+            1,6
+            2,0
+        "};
+        assert_eq!(min_steps(example, Point::new(7, 7), 12)?, 22);
+        Ok(())
+    }
+}
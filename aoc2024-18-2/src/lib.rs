@@ -0,0 +1,161 @@
+use common::{
+    geom::Point,
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
+use nom::{
+    character::complete::{char, i64},
+    error::context,
+    sequence::separated_pair,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("byte at {},{} falls outside the memory space", .0.x, .0.y)]
+    OutOfBounds(Point),
+    #[error("the exit is never cut off")]
+    NeverBlocked,
+    #[error("no path to the exit even before any bytes fall")]
+    NoPath,
+}
+
+/// The size of the real memory space; the puzzle example uses 7 by 7.
+pub const MEMORY: Point = Point::new(71, 71);
+
+pub fn first_blocking_byte(input: &str, size: Point) -> Result<String> {
+    let bytes = Solver::parse(input, Mode::Strict)?;
+    bytes.first_blocking(size)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 18;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "RAM Run";
+    type Input = Bytes;
+    type Answer = String;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Bytes> {
+        Ok(Bytes(parse::lines(input.lines(), mode, byte)?))
+    }
+
+    fn solve(bytes: Bytes) -> Result<String> {
+        bytes.first_blocking(MEMORY)
+    }
+}
+
+fn byte(input: &str) -> parse::IResult<'_, Point> {
+    let (rest, (x, y)) = context("`<x>,<y>` position", separated_pair(i64, char(','), i64))(input)?;
+    Ok((rest, Point::new(x, y)))
+}
+
+pub struct Bytes(Vec<Point>);
+
+impl Bytes {
+    fn corrupted(&self, size: Point, fallen: usize) -> Result<Grid<bool>> {
+        let (width, height) = size.as_indices().unwrap_or_default();
+        let mut memory = Grid::new(width, height, false);
+        for &byte in self.0.iter().take(fallen) {
+            *memory.get_mut(&byte).ok_or(Error::OutOfBounds(byte))? = true;
+        }
+        Ok(memory)
+    }
+
+    fn reaches_exit(&self, size: Point, fallen: usize) -> Result<bool> {
+        let memory = self.corrupted(size, fallen)?;
+        let exit = size - Point::new(1, 1);
+        let mut seen = Grid::new(memory.width(), memory.height(), false);
+        let mut stack = Vec::new();
+        if memory.get(&Point::ORIGIN) == Some(&false) {
+            seen[Point::ORIGIN] = true;
+            stack.push(Point::ORIGIN);
+        }
+        while let Some(position) = stack.pop() {
+            if position == exit {
+                return Ok(true);
+            }
+            for neighbour in memory.neighbours4(position) {
+                if !memory[neighbour] && !seen[neighbour] {
+                    seen[neighbour] = true;
+                    stack.push(neighbour);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Binary searches for the number of fallen bytes at which the exit
+    /// first becomes unreachable; the last of those bytes is the culprit.
+    fn first_blocking(&self, size: Point) -> Result<String> {
+        let (mut open, mut blocked) = (0, self.0.len());
+        if !self.reaches_exit(size, open)? {
+            return Err(Error::NoPath);
+        }
+        if self.reaches_exit(size, blocked)? {
+            return Err(Error::NeverBlocked);
+        }
+        while blocked - open > 1 {
+            let fallen = open + (blocked - open) / 2;
+            if self.reaches_exit(size, fallen)? {
+                open = fallen;
+            } else {
+                blocked = fallen;
+            }
+        }
+        let Point { x, y } = self.0[blocked - 1];
+        Ok(format!("{x},{y}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            5,4
+            4,2
+            4,5
+            3,0
+            2,1
+            6,3
+            2,4
+            1,5
+            0,6
+            3,3
+            2,6
+            5,1
+            1,2
+            5,5
+            2,5
+            6,5
+            1,4
+            0,4
+            6,4
+            1,1
+            6,1
+            1,0
+            0,5
+            1,6
+            2,0
+        "};
+        assert_eq!(first_blocking_byte(example, Point::new(7, 7))?, "6,1");
+        Ok(())
+    }
+
+    #[test]
+    fn no_memory_space() {
+        let result = first_blocking_byte("", Point::new(0, 0));
+        assert!(matches!(result, Err(Error::NoPath)));
+    }
+}
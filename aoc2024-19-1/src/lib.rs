@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete::{line_ending, multispace1},
+    error::context,
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+pub fn num_possible_designs(input: &str) -> Result<usize> {
+    Solver::run(input, Mode::Strict)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 19;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Linen Layout";
+    type Input = Onsen;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Onsen> {
+        Ok(parse::complete(input, mode, Onsen::parse)?)
+    }
+
+    fn solve(onsen: Onsen) -> Result<usize> {
+        let mut cache = Cache::default();
+        Ok(onsen
+            .designs
+            .iter()
+            .filter(|design| onsen.arrangements(design, &mut cache) > 0)
+            .count())
+    }
+}
+
+pub struct Onsen {
+    towels: Vec<String>,
+    designs: Vec<String>,
+}
+
+type Cache<'a> = HashMap<&'a str, u64>;
+
+impl Onsen {
+    /// Number of ways to lay towels end to end to make `design`.
+    fn arrangements<'a>(&self, design: &'a str, cache: &mut Cache<'a>) -> u64 {
+        if design.is_empty() {
+            return 1;
+        }
+        if let Some(answer) = cache.get(design) {
+            return *answer;
+        }
+        let ret = self
+            .towels
+            .iter()
+            .filter_map(|towel| design.strip_prefix(towel.as_str()))
+            .map(|rest| self.arrangements(rest, cache))
+            .sum();
+        cache.insert(design, ret);
+        ret
+    }
+}
+
+impl Parse for Onsen {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let stripes = || context("stripe colours (`w`, `u`, `b`, `r` or `g`)", is_a("wubrg"));
+        let (rest, (towels, designs)) = separated_pair(
+            separated_list1(tag(", "), stripes()),
+            multispace1,
+            separated_list1(line_ending, stripes()),
+        )(input)?;
+        let owned = |strs: Vec<&str>| strs.into_iter().map(String::from).collect();
+        Ok((
+            rest,
+            Self {
+                towels: owned(towels),
+                designs: owned(designs),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            r, wr, b, g, bwu, rb, gb, br
+
+            brwrr
+            bggr
+            gbbr
+            rrbgbr
+            ubwu
+            bwurrg
+            brgr
+            bbrgwb
+        "};
+        assert_eq!(num_possible_designs(example)?, 6);
+        Ok(())
+    }
+}
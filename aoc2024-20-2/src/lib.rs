@@ -0,0 +1,148 @@
+use common::{
+    geom::Point,
+    grid::Grid,
+    parse::{self, Mode},
+    solution::Solution,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("missing start position ('S')")]
+    MissingStart,
+    #[error("missing end position ('E')")]
+    MissingEnd,
+    #[error("track from start doesn't reach the end")]
+    BrokenTrack,
+}
+
+/// The longest a cheat may last, in picoseconds.
+const CHEAT: i64 = 20;
+
+/// The saving, in picoseconds, a cheat needs to count for the real input.
+pub const THRESHOLD: usize = 100;
+
+pub fn num_cheats(it: impl Iterator<Item = String>, threshold: usize) -> Result<usize> {
+    Ok(Racetrack::parse(it)?.num_cheats(threshold))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 20;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Race Condition";
+    type Input = Racetrack;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, _mode: Mode) -> Result<Racetrack> {
+        Racetrack::parse(input.lines())
+    }
+
+    fn solve(racetrack: Racetrack) -> Result<usize> {
+        Ok(racetrack.num_cheats(THRESHOLD))
+    }
+}
+
+pub struct Racetrack {
+    /// Every track position, in order from the start.
+    track: Vec<Point>,
+    /// How far along the track each position is, if it's on the track.
+    distances: Grid<Option<usize>>,
+}
+
+impl Racetrack {
+    fn parse<S: AsRef<str>>(rows: impl Iterator<Item = S>) -> Result<Self> {
+        let map = Grid::from_lines(rows, |ch| ch)?;
+        let start = map.find(&'S').ok_or(Error::MissingStart)?;
+        let end = map.find(&'E').ok_or(Error::MissingEnd)?;
+
+        let mut track = vec![start];
+        let mut distances = Grid::new(map.width(), map.height(), None);
+        distances[start] = Some(0);
+        let mut previous = None;
+        let mut position = start;
+        while position != end {
+            let next = map
+                .neighbours4(position)
+                .find(|&next| map[next] != '#' && Some(next) != previous)
+                .ok_or(Error::BrokenTrack)?;
+            // Coming back to the track so far means going round a loop that
+            // never reaches the end.
+            if distances[next].is_some() {
+                return Err(Error::BrokenTrack);
+            }
+            distances[next] = Some(track.len());
+            previous = Some(position);
+            position = next;
+            track.push(next);
+        }
+        Ok(Self { track, distances })
+    }
+
+    /// Cheats saving at least `threshold`, found by looking only within
+    /// cheating range of each track position rather than at every pair.
+    fn num_cheats(&self, threshold: usize) -> usize {
+        let offsets: Vec<_> = (-CHEAT..=CHEAT)
+            .flat_map(|dy| {
+                let reach = CHEAT - dy.abs();
+                (-reach..=reach).map(move |dx| Point::new(dx, dy))
+            })
+            .collect();
+        self.track
+            .iter()
+            .enumerate()
+            .map(|(from, &start)| {
+                offsets
+                    .iter()
+                    .filter(|&&offset| {
+                        let Some(&Some(to)) = self.distances.get(&(start + offset)) else {
+                            return false;
+                        };
+                        let cheat = offset.manhattan(&Point::ORIGIN) as usize;
+                        to.checked_sub(from + cheat)
+                            .is_some_and(|saving| saving > 0 && saving >= threshold)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        ###############
+        #...#...#.....#
+        #.#.#.#.#.###.#
+        #S#...#.#.#...#
+        #######.#.#.###
+        #######.#.#...#
+        #######.#.###.#
+        ###..E#...#...#
+        ###.#######.###
+        #...###...#...#
+        #.#####.#.###.#
+        #.#...#.#.#...#
+        #.#.#.#.#.#.###
+        #...#...#...###
+        ###############
+    "};
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let cheats = |threshold| num_cheats(EXAMPLE.lines().map(String::from), threshold);
+        assert_eq!(cheats(76)?, 3);
+        assert_eq!(cheats(72)?, 29);
+        assert_eq!(cheats(50)?, 285);
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use std::{collections::HashMap, iter};
+
+use common::{
+    geom::Point,
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    character::complete::{char, u64},
+    combinator::consumed,
+    error::context,
+    sequence::terminated,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+/// Robots, each at a directional keypad, between you and the numeric keypad.
+const ROBOTS: usize = 2;
+
+const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+pub fn complexity_sum(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Codes(parse::lines(it, mode, Code::parse)?))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 21;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Keypad Conundrum";
+    type Input = Codes;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Codes> {
+        Ok(Codes(parse::lines(input.lines(), mode, Code::parse)?))
+    }
+
+    fn solve(codes: Codes) -> Result<u64> {
+        let mut controls = Controls::new(ROBOTS);
+        Ok(codes
+            .0
+            .iter()
+            .map(|code| controls.presses(&code.keys) * code.value)
+            .sum())
+    }
+}
+
+pub struct Codes(Vec<Code>);
+
+struct Code {
+    keys: String,
+    value: u64,
+}
+
+impl Parse for Code {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let (rest, (digits, value)) = terminated(
+            context("door code digits", consumed(u64)),
+            context("`A` ending door code", char('A')),
+        )(input)?;
+        Ok((
+            rest,
+            Self {
+                keys: format!("{digits}A"),
+                value,
+            },
+        ))
+    }
+}
+
+struct Keypad {
+    keys: HashMap<char, Point>,
+    gap: Point,
+}
+
+impl Keypad {
+    /// A keypad laid out as rows of keys, with a space for the gap.
+    fn new(layout: &[&str]) -> Self {
+        let mut keys = HashMap::new();
+        let mut gap = Point::new(-1, -1);
+        for (y, row) in layout.iter().enumerate() {
+            for (x, key) in row.chars().enumerate() {
+                let position = Point::from_indices(x, y);
+                match key {
+                    ' ' => gap = position,
+                    _ => {
+                        keys.insert(key, position);
+                    }
+                }
+            }
+        }
+        Self { keys, gap }
+    }
+
+    /// Directional key sequences, each ending in `A`, that move an arm from
+    /// `from` to `to` and press it. Only routes going straight along one axis
+    /// then the other are worth considering, as zig-zagging costs extra
+    /// presses further up the chain; those passing over the gap are left out.
+    fn routes(&self, from: char, to: char) -> Vec<String> {
+        let (from, to) = (self.keys[&from], self.keys[&to]);
+        let delta = to - from;
+        let horizontal =
+            if delta.x < 0 { "<" } else { ">" }.repeat(delta.x.unsigned_abs() as usize);
+        let vertical = if delta.y < 0 { "^" } else { "v" }.repeat(delta.y.unsigned_abs() as usize);
+        let mut routes = Vec::new();
+        if Point::new(to.x, from.y) != self.gap {
+            routes.push(format!("{horizontal}{vertical}A"));
+        }
+        if Point::new(from.x, to.y) != self.gap {
+            routes.push(format!("{vertical}{horizontal}A"));
+        }
+        routes
+    }
+}
+
+type Cache = HashMap<(char, char, usize), u64>;
+
+struct Controls {
+    numeric: Keypad,
+    directional: Keypad,
+    robots: usize,
+    cache: Cache,
+}
+
+/// Consecutive pairs of keys an arm moves between to type `keys`, starting
+/// from `A`.
+fn moves(keys: &str) -> impl Iterator<Item = (char, char)> + '_ {
+    iter::once('A').chain(keys.chars()).zip(keys.chars())
+}
+
+impl Controls {
+    fn new(robots: usize) -> Self {
+        Self {
+            numeric: Keypad::new(&NUMERIC),
+            directional: Keypad::new(&DIRECTIONAL),
+            robots,
+            cache: Cache::default(),
+        }
+    }
+
+    /// Fewest presses of your own keypad to type `code` on the numeric keypad.
+    fn presses(&mut self, code: &str) -> u64 {
+        moves(code)
+            .map(|(from, to)| {
+                let routes = self.numeric.routes(from, to);
+                routes
+                    .iter()
+                    .map(|route| self.directional_presses(route, self.robots))
+                    .min()
+                    .unwrap_or_default()
+            })
+            .sum()
+    }
+
+    /// Fewest presses of your own keypad to type `keys` on a directional
+    /// keypad with `depth` more directional keypads between it and you.
+    fn directional_presses(&mut self, keys: &str, depth: usize) -> u64 {
+        if depth == 0 {
+            return keys.len() as u64;
+        }
+        moves(keys)
+            .map(|(from, to)| self.move_presses(from, to, depth))
+            .sum()
+    }
+
+    fn move_presses(&mut self, from: char, to: char, depth: usize) -> u64 {
+        if let Some(answer) = self.cache.get(&(from, to, depth)) {
+            return *answer;
+        }
+        let routes = self.directional.routes(from, to);
+        let ret = routes
+            .iter()
+            .map(|route| self.directional_presses(route, depth - 1))
+            .min()
+            .unwrap_or_default();
+        self.cache.insert((from, to, depth), ret);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            029A
+            980A
+            179A
+            456A
+            379A
+        "};
+        assert_eq!(
+            complexity_sum(example.lines().map(String::from), Mode::Strict)?,
+            126384
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn presses_per_code() {
+        let mut controls = Controls::new(ROBOTS);
+        assert_eq!(controls.presses("029A"), 68);
+        assert_eq!(controls.presses("379A"), 64);
+    }
+}
@@ -0,0 +1,119 @@
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{character::complete::u64, error::context};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+const SECRETS: usize = 2000;
+
+pub fn secret_sum(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Buyers::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 22;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Monkey Market";
+    type Input = Buyers;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Buyers> {
+        Buyers::parse(input.lines(), mode)
+    }
+
+    fn solve(buyers: Buyers) -> Result<u64> {
+        Ok(buyers
+            .0
+            .into_iter()
+            .filter_map(|mut secret| secret.nth(SECRETS))
+            .map(|secret| secret.0)
+            .sum())
+    }
+}
+
+pub struct Buyers(Vec<Secret>);
+
+impl Buyers {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> Result<Self> {
+        Ok(Self(parse::lines(lines, mode, Secret::parse)?))
+    }
+}
+
+/// Secrets are kept below this, a power of two, so overflow above it when
+/// mixing doesn't change the result.
+const PRUNE: u64 = 16777216;
+
+/// A buyer's secret number, iterating over the sequence of secrets starting
+/// with itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Secret(u64);
+
+impl Parse for Secret {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let (rest, secret) = context("initial secret", u64)(input)?;
+        Ok((rest, Self(secret)))
+    }
+}
+
+impl Iterator for Secret {
+    type Item = Secret;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = *self;
+        let mix_prune = |secret: u64, value: u64| (secret ^ value) % PRUNE;
+        let mut secret = mix_prune(self.0, self.0.wrapping_mul(64));
+        secret = mix_prune(secret, secret / 32);
+        secret = mix_prune(secret, secret.wrapping_mul(2048));
+        self.0 = secret;
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            1
+            10
+            100
+            2024
+        "};
+        assert_eq!(
+            secret_sum(example.lines().map(String::from), Mode::Strict)?,
+            37327623
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unpruned_secret() -> Result<()> {
+        let secrets = ["20000000", "1000000000000000000"].map(String::from);
+        assert_eq!(
+            secret_sum(secrets.into_iter(), Mode::Strict)?,
+            11045353 + 5430321
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sequence() {
+        let secrets: Vec<_> = Secret(123).skip(1).take(3).map(|s| s.0).collect();
+        assert_eq!(secrets, [15887950, 16495136, 527345]);
+    }
+}
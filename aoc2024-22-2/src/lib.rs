@@ -0,0 +1,148 @@
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{character::complete::u64, error::context};
+use rayon::prelude::*;
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+const SECRETS: usize = 2000;
+
+pub fn most_bananas(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Buyers::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 22;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Monkey Market";
+    type Input = Buyers;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Buyers> {
+        Buyers::parse(input.lines(), mode)
+    }
+
+    fn solve(buyers: Buyers) -> Result<u64> {
+        let (bananas, _) = buyers
+            .0
+            .par_iter()
+            .enumerate()
+            .fold(
+                || (vec![0; WINDOWS], vec![None; WINDOWS]),
+                |(mut bananas, mut sold_by), (buyer, &secret)| {
+                    // Only the first time a buyer sees each window counts.
+                    for (window, price) in secret.windows() {
+                        if sold_by[window] != Some(buyer) {
+                            sold_by[window] = Some(buyer);
+                            bananas[window] += u64::from(price);
+                        }
+                    }
+                    (bananas, sold_by)
+                },
+            )
+            .reduce(
+                || (vec![0; WINDOWS], Vec::new()),
+                |(mut total, _), (bananas, _)| {
+                    for (total, bananas) in total.iter_mut().zip(bananas) {
+                        *total += bananas;
+                    }
+                    (total, Vec::new())
+                },
+            );
+        Ok(bananas.into_iter().max().unwrap_or_default())
+    }
+}
+
+pub struct Buyers(Vec<Secret>);
+
+impl Buyers {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> Result<Self> {
+        Ok(Self(parse::lines(lines, mode, Secret::parse)?))
+    }
+}
+
+/// Secrets are kept below this, a power of two, so overflow above it when
+/// mixing doesn't change the result.
+const PRUNE: u64 = 16777216;
+
+/// A buyer's secret number, iterating over the sequence of secrets starting
+/// with itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Secret(u64);
+
+impl Parse for Secret {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let (rest, secret) = context("initial secret", u64)(input)?;
+        Ok((rest, Self(secret)))
+    }
+}
+
+impl Iterator for Secret {
+    type Item = Secret;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = *self;
+        let mix_prune = |secret: u64, value: u64| (secret ^ value) % PRUNE;
+        let mut secret = mix_prune(self.0, self.0.wrapping_mul(64));
+        secret = mix_prune(secret, secret / 32);
+        secret = mix_prune(secret, secret.wrapping_mul(2048));
+        self.0 = secret;
+        Some(current)
+    }
+}
+
+/// Distinct windows of four price changes, each in `-9..=9`.
+const WINDOWS: usize = 19 * 19 * 19 * 19;
+
+impl Secret {
+    fn price(self) -> u8 {
+        (self.0 % 10) as u8
+    }
+
+    /// Each window of four price changes, encoded in base 19, with the price
+    /// at its end.
+    fn windows(self) -> impl Iterator<Item = (usize, u8)> {
+        let mut prices = self.take(SECRETS + 1).map(Secret::price);
+        let mut previous = prices.next().unwrap_or_default();
+        let mut window = 0;
+        prices.enumerate().filter_map(move |(idx, price)| {
+            let change = (i16::from(price) - i16::from(previous) + 9) as usize;
+            previous = price;
+            window = (window * 19 + change) % WINDOWS;
+            (idx >= 3).then_some((window, price))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            1
+            2
+            3
+            2024
+        "};
+        assert_eq!(
+            most_bananas(example.lines().map(String::from), Mode::Strict)?,
+            23
+        );
+        Ok(())
+    }
+}
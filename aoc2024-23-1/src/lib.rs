@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    parse::{self, Mode},
+    solution::Solution,
+};
+use nom::{
+    character::complete::{alphanumeric1, char},
+    combinator::verify,
+    error::context,
+    sequence::separated_pair,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+pub fn num_t_triangles(it: impl Iterator<Item = String>, mode: Mode) -> Result<usize> {
+    Solver::solve(Network::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 23;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "LAN Party";
+    type Input = Network;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Network> {
+        Network::parse(input.lines(), mode)
+    }
+
+    fn solve(network: Network) -> Result<usize> {
+        Ok(network
+            .triangles()
+            .filter(|triangle| triangle.iter().any(|host| host.starts_with('t')))
+            .count())
+    }
+}
+
+fn connection(input: &str) -> parse::IResult<'_, (&str, &str)> {
+    let hosts = separated_pair(
+        context("host name", alphanumeric1),
+        context("`-` between hosts", char('-')),
+        context("host name", alphanumeric1),
+    );
+    context("two different hosts", verify(hosts, |(a, b)| a != b))(input)
+}
+
+#[derive(Debug, Default)]
+pub struct Network(HashMap<String, HashSet<String>>);
+
+impl Network {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> Result<Self> {
+        let mut network = Self::default();
+        let connections = parse::lines(lines, mode, |input| {
+            let (rest, (a, b)) = connection(input)?;
+            Ok((rest, (a.to_string(), b.to_string())))
+        })?;
+        for (a, b) in connections {
+            network.0.entry(a.clone()).or_default().insert(b.clone());
+            network.0.entry(b).or_default().insert(a);
+        }
+        Ok(network)
+    }
+
+    /// Every set of three mutually connected hosts, once each, in sorted
+    /// order.
+    fn triangles(&self) -> impl Iterator<Item = [&str; 3]> + '_ {
+        self.0.iter().flat_map(move |(a, neighbours)| {
+            neighbours
+                .iter()
+                .filter(move |b| a < *b)
+                .flat_map(move |b| {
+                    neighbours
+                        .iter()
+                        .filter(move |c| b < *c && self.0[b].contains(*c))
+                        .map(move |c| [a.as_str(), b.as_str(), c.as_str()])
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            kh-tc
+            qp-kh
+            de-cg
+            ka-co
+            yn-aq
+            qp-ub
+            cg-tb
+            vc-aq
+            tb-ka
+            wh-tc
+            yn-cg
+            kh-ub
+            ta-co
+            de-co
+            tc-td
+            tb-wq
+            wh-td
+            ta-ka
+            td-qp
+            aq-cg
+            wq-ub
+            ub-vc
+            de-ta
+            wq-aq
+            wq-vc
+            wh-yn
+            ka-de
+            kh-ta
+            co-tc
+            wh-qp
+            tb-vc
+            td-yn
+        "};
+        assert_eq!(
+            num_t_triangles(example.lines().map(String::from), Mode::Strict)?,
+            7
+        );
+        Ok(())
+    }
+}
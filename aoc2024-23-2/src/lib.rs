@@ -0,0 +1,196 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    parse::{self, Mode},
+    solution::Solution,
+};
+use nom::{
+    character::complete::{alphanumeric1, char},
+    combinator::verify,
+    error::context,
+    sequence::separated_pair,
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+}
+
+pub fn password(it: impl Iterator<Item = String>, mode: Mode) -> Result<String> {
+    Solver::solve(Network::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 23;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "LAN Party";
+    type Input = Network;
+    type Answer = String;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Network> {
+        Network::parse(input.lines(), mode)
+    }
+
+    fn solve(network: Network) -> Result<String> {
+        let mut largest = Vec::new();
+        network.bron_kerbosch(
+            Vec::new(),
+            network.0.keys().map(String::as_str).collect(),
+            HashSet::new(),
+            &mut largest,
+        );
+        largest.sort_unstable();
+        Ok(largest.join(","))
+    }
+}
+
+fn connection(input: &str) -> parse::IResult<'_, (&str, &str)> {
+    let hosts = separated_pair(
+        context("host name", alphanumeric1),
+        context("`-` between hosts", char('-')),
+        context("host name", alphanumeric1),
+    );
+    context("two different hosts", verify(hosts, |(a, b)| a != b))(input)
+}
+
+#[derive(Debug, Default)]
+pub struct Network(HashMap<String, HashSet<String>>);
+
+impl Network {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> Result<Self> {
+        let mut network = Self::default();
+        let connections = parse::lines(lines, mode, |input| {
+            let (rest, (a, b)) = connection(input)?;
+            Ok((rest, (a.to_string(), b.to_string())))
+        })?;
+        for (a, b) in connections {
+            network.0.entry(a.clone()).or_default().insert(b.clone());
+            network.0.entry(b).or_default().insert(a);
+        }
+        Ok(network)
+    }
+
+    /// Bron–Kerbosch with pivoting, keeping the largest maximal clique found
+    /// in `largest`. `clique` is the clique being grown, `candidates` the
+    /// hosts that could extend it and `excluded` those already tried.
+    fn bron_kerbosch<'a>(
+        &'a self,
+        clique: Vec<&'a str>,
+        mut candidates: HashSet<&'a str>,
+        mut excluded: HashSet<&'a str>,
+        largest: &mut Vec<&'a str>,
+    ) {
+        let Some(pivot) = candidates
+            .union(&excluded)
+            .max_by_key(|host| self.0[**host].len())
+        else {
+            if clique.len() > largest.len() {
+                *largest = clique;
+            }
+            return;
+        };
+        // Any maximal clique contains the pivot or one of its non-neighbours.
+        let pivot_neighbours = &self.0[*pivot];
+        let branches: Vec<_> = candidates
+            .iter()
+            .copied()
+            .filter(|host| !pivot_neighbours.contains(*host))
+            .collect();
+        for host in branches {
+            let neighbours = &self.0[host];
+            let mut grown = clique.clone();
+            grown.push(host);
+            self.bron_kerbosch(
+                grown,
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|other| neighbours.contains(*other))
+                    .collect(),
+                excluded
+                    .iter()
+                    .copied()
+                    .filter(|other| neighbours.contains(*other))
+                    .collect(),
+                largest,
+            );
+            candidates.remove(host);
+            excluded.insert(host);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            kh-tc
+            qp-kh
+            de-cg
+            ka-co
+            yn-aq
+            qp-ub
+            cg-tb
+            vc-aq
+            tb-ka
+            wh-tc
+            yn-cg
+            kh-ub
+            ta-co
+            de-co
+            tc-td
+            tb-wq
+            wh-td
+            ta-ka
+            td-qp
+            aq-cg
+            wq-ub
+            ub-vc
+            de-ta
+            wq-aq
+            wq-vc
+            wh-yn
+            ka-de
+            kh-ta
+            co-tc
+            wh-qp
+            tb-vc
+            td-yn
+        "};
+        assert_eq!(
+            password(example.lines().map(String::from), Mode::Strict)?,
+            "co,de,ka,ta"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn self_connection() -> Result<()> {
+        let example = indoc! {"
+            ab-ab
+            ab-cd
+        "};
+        let lines = || example.lines().map(String::from);
+        let Err(Error::Parse(parse::Error::Diagnostics(errors))) = password(lines(), Mode::Strict)
+        else {
+            panic!("expected diagnostics");
+        };
+        assert_eq!(
+            (errors[0].line, errors[0].expected.as_str()),
+            (1, "two different hosts")
+        );
+        assert_eq!(password(lines(), Mode::Lenient)?, "ab,cd");
+        Ok(())
+    }
+}
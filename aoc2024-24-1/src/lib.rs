@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, char, space1},
+    combinator::value,
+    error::context,
+    sequence::{separated_pair, tuple},
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("wire {0} never receives a signal")]
+    NoSignal(String),
+    #[error("wire {0} doesn't name a bit of a 64-bit number")]
+    BadBit(String),
+}
+
+pub fn z_number(it: impl Iterator<Item = String>, mode: Mode) -> Result<u64> {
+    Solver::solve(Device::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 24;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Crossed Wires";
+    type Input = Device;
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Device> {
+        Device::parse(input.lines(), mode)
+    }
+
+    fn solve(device: Device) -> Result<u64> {
+        let signals = device.simulate();
+        let mut z = 0;
+        for wire in device.outputs().filter(|wire| wire.starts_with('z')) {
+            let signal = *signals
+                .get(wire)
+                .ok_or_else(|| Error::NoSignal(wire.to_string()))?;
+            z |= wire[1..]
+                .parse()
+                .ok()
+                .and_then(|bit| u64::from(signal).checked_shl(bit))
+                .ok_or_else(|| Error::BadBit(wire.to_string()))?;
+        }
+        Ok(z)
+    }
+}
+
+pub struct Device {
+    initial: Vec<(String, bool)>,
+    gates: Vec<Gate>,
+}
+
+impl Device {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> Result<Self> {
+        let mut lines = lines.enumerate().map(|(idx, line)| (idx + 1, line));
+        let initial = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.as_ref().is_empty());
+        let initial = parse::numbered_lines(initial, mode, wire);
+        let gates = parse::numbered_lines(lines, mode, Gate::parse);
+        let (initial, gates) = parse::both(initial, gates)?;
+        Ok(Self { initial, gates })
+    }
+
+    fn outputs(&self) -> impl Iterator<Item = &str> {
+        self.gates.iter().map(|gate| gate.output.as_str())
+    }
+
+    /// Signals on every wire that gets one, firing each gate once both its
+    /// inputs are known.
+    fn simulate(&self) -> HashMap<&str, bool> {
+        let mut signals: HashMap<_, _> = self
+            .initial
+            .iter()
+            .map(|(wire, signal)| (wire.as_str(), *signal))
+            .collect();
+        let mut pending: Vec<_> = self.gates.iter().collect();
+        loop {
+            let before = pending.len();
+            pending.retain(|gate| {
+                let [a, b] = &gate.inputs;
+                let (Some(&a), Some(&b)) = (signals.get(a.as_str()), signals.get(b.as_str()))
+                else {
+                    return true;
+                };
+                signals.insert(&gate.output, gate.op.apply(a, b));
+                false
+            });
+            if pending.len() == before {
+                return signals;
+            }
+        }
+    }
+}
+
+fn wire(input: &str) -> parse::IResult<'_, (String, bool)> {
+    let (rest, (name, signal)) = separated_pair(
+        context("wire name", alphanumeric1),
+        tag(": "),
+        context(
+            "signal (`0` or `1`)",
+            alt((value(false, char('0')), value(true, char('1')))),
+        ),
+    )(input)?;
+    Ok((rest, (name.to_string(), signal)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::And => a & b,
+            Self::Or => a | b,
+            Self::Xor => a ^ b,
+        }
+    }
+}
+
+struct Gate {
+    inputs: [String; 2],
+    op: Op,
+    output: String,
+}
+
+impl Parse for Gate {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let op = alt((
+            value(Op::And, tag("AND")),
+            value(Op::Or, tag("OR")),
+            value(Op::Xor, tag("XOR")),
+        ));
+        let (rest, (a, _, op, _, b, _, output)) = tuple((
+            context("input wire", alphanumeric1),
+            space1,
+            context("gate (`AND`, `OR` or `XOR`)", op),
+            space1,
+            context("input wire", alphanumeric1),
+            context("` -> ` before output wire", tag(" -> ")),
+            context("output wire", alphanumeric1),
+        ))(input)?;
+        Ok((
+            rest,
+            Self {
+                inputs: [a.to_string(), b.to_string()],
+                op,
+                output: output.to_string(),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn small_example() -> Result<()> {
+        let example = indoc! {"
+            x00: 1
+            x01: 1
+            x02: 1
+            y00: 0
+            y01: 1
+            y02: 0
+
+            x00 AND y00 -> z00
+            x01 XOR y01 -> z01
+            x02 OR y02 -> z02
+        "};
+        assert_eq!(
+            z_number(example.lines().map(String::from), Mode::Strict)?,
+            4
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bad_bits() {
+        for wire in ["z64", "zed"] {
+            let example = format!("x00: 1\n\nx00 OR x00 -> {wire}\n");
+            let result = z_number(example.lines().map(String::from), Mode::Strict);
+            assert!(matches!(result, Err(Error::BadBit(bad)) if bad == wire));
+        }
+    }
+
+    #[test]
+    fn full_example() -> Result<()> {
+        let example = indoc! {"
+            x00: 1
+            x01: 0
+            x02: 1
+            x03: 1
+            x04: 0
+            y00: 1
+            y01: 1
+            y02: 1
+            y03: 1
+            y04: 1
+
+            ntg XOR fgs -> mjb
+            y02 OR x01 -> tnw
+            kwq OR kpj -> z05
+            x00 OR x03 -> fst
+            tgd XOR rvg -> z01
+            vdt OR tnw -> bfw
+            bfw AND frj -> z10
+            ffh OR nrd -> bqk
+            y00 AND y03 -> djm
+            y03 OR y00 -> psh
+            bqk OR frj -> z08
+            tnw OR fst -> frj
+            gnj AND tgd -> z11
+            bfw XOR mjb -> z00
+            x03 OR x00 -> vdt
+            gnj AND wpb -> z02
+            x04 AND y00 -> kjc
+            djm OR pbm -> qhw
+            nrd AND vdt -> hwm
+            kjc AND fst -> rvg
+            y04 OR y02 -> fgs
+            y01 AND x02 -> pbm
+            ntg OR kjc -> kwq
+            psh XOR fgs -> tgd
+            qhw XOR tgd -> z09
+            pbm OR djm -> kpj
+            x03 XOR y03 -> ffh
+            x00 XOR y04 -> ntg
+            bfw OR bqk -> z06
+            nrd XOR fgs -> wpb
+            frj XOR qhw -> z04
+            bqk OR frj -> z07
+            y03 OR x01 -> nrd
+            hwm AND bqk -> z03
+            tgd XOR rvg -> z12
+            tnw OR pbm -> gnj
+        "};
+        assert_eq!(
+            z_number(example.lines().map(String::from), Mode::Strict)?,
+            2024
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+use common::{
+    parse::{self, Mode, Parse},
+    solution::Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, char, space1},
+    combinator::value,
+    error::context,
+    sequence::{separated_pair, tuple},
+};
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("{0}")]
+    Parse(#[from] parse::Error),
+    #[error("found {0} misplaced gate outputs, expected {expected}", expected = 2 * SWAPS)]
+    UnexpectedMiswiring(usize),
+}
+
+/// Pairs of gates whose outputs have been swapped.
+const SWAPS: usize = 4;
+
+pub fn swapped_wires(it: impl Iterator<Item = String>, mode: Mode) -> Result<String> {
+    Solver::solve(Device::parse(it, mode)?)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 24;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Crossed Wires";
+    type Input = Device;
+    type Answer = String;
+    type Error = Error;
+
+    fn parse(input: &str, mode: Mode) -> Result<Device> {
+        Device::parse(input.lines(), mode)
+    }
+
+    fn solve(device: Device) -> Result<String> {
+        let mut miswired = device.miswired();
+        if miswired.len() != 2 * SWAPS {
+            return Err(Error::UnexpectedMiswiring(miswired.len()));
+        }
+        miswired.sort_unstable();
+        Ok(miswired.join(","))
+    }
+}
+
+pub struct Device {
+    gates: Vec<Gate>,
+}
+
+impl Device {
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>, mode: Mode) -> Result<Self> {
+        let mut lines = lines.enumerate().map(|(idx, line)| (idx + 1, line));
+        let initial = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.as_ref().is_empty());
+        // The initial signals don't matter to the wiring, but are still
+        // checked.
+        let initial = parse::numbered_lines(initial, mode, wire);
+        let gates = parse::numbered_lines(lines, mode, Gate::parse);
+        let (_, gates) = parse::both(initial, gates)?;
+        Ok(Self { gates })
+    }
+
+    /// Gate outputs that are out of place in a ripple-carry adder of `x` and
+    /// `y` into `z`, whose bit 0 is a half adder and every later bit `i` is
+    ///
+    /// ```text
+    /// z_i     = (x_i XOR y_i) XOR carry_i-1
+    /// carry_i = (x_i AND y_i) OR ((x_i XOR y_i) AND carry_i-1)
+    /// ```
+    ///
+    /// with the final carry as the top bit of `z`.
+    fn miswired(&self) -> Vec<&str> {
+        let top = self
+            .gates
+            .iter()
+            .map(|gate| gate.output.as_str())
+            .filter(|wire| wire.starts_with('z'))
+            .max();
+        let feeds = |wire: &str, op: Op| {
+            self.gates
+                .iter()
+                .any(|gate| gate.op == op && gate.inputs.iter().any(|input| input == wire))
+        };
+        self.gates
+            .iter()
+            .filter(|gate| {
+                let output = gate.output.as_str();
+                let from_inputs = gate
+                    .inputs
+                    .iter()
+                    .all(|wire| wire.starts_with('x') || wire.starts_with('y'));
+                let first = from_inputs && gate.inputs.iter().all(|wire| wire.ends_with("00"));
+                if Some(output) == top {
+                    gate.op != Op::Or
+                } else if output.starts_with('z') {
+                    gate.op != Op::Xor || (from_inputs && !first)
+                } else {
+                    match gate.op {
+                        // Only the sum of a carry and an input pair gives a
+                        // `z` bit; an input pair's sum goes on to that.
+                        Op::Xor => !from_inputs || !feeds(output, Op::Xor),
+                        // Both halves of a carry go into its `OR`.
+                        Op::And => !first && !feeds(output, Op::Or),
+                        Op::Or => false,
+                    }
+                }
+            })
+            .map(|gate| gate.output.as_str())
+            .collect()
+    }
+}
+
+fn wire(input: &str) -> parse::IResult<'_, (String, bool)> {
+    let (rest, (name, signal)) = separated_pair(
+        context("wire name", alphanumeric1),
+        tag(": "),
+        context(
+            "signal (`0` or `1`)",
+            alt((value(false, char('0')), value(true, char('1')))),
+        ),
+    )(input)?;
+    Ok((rest, (name.to_string(), signal)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+struct Gate {
+    inputs: [String; 2],
+    op: Op,
+    output: String,
+}
+
+impl Parse for Gate {
+    fn parse(input: &str) -> parse::IResult<'_, Self> {
+        let op = alt((
+            value(Op::And, tag("AND")),
+            value(Op::Or, tag("OR")),
+            value(Op::Xor, tag("XOR")),
+        ));
+        let (rest, (a, _, op, _, b, _, output)) = tuple((
+            context("input wire", alphanumeric1),
+            space1,
+            context("gate (`AND`, `OR` or `XOR`)", op),
+            space1,
+            context("input wire", alphanumeric1),
+            context("` -> ` before output wire", tag(" -> ")),
+            context("output wire", alphanumeric1),
+        ))(input)?;
+        Ok((
+            rest,
+            Self {
+                inputs: [a.to_string(), b.to_string()],
+                op,
+                output: output.to_string(),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A correct `bits`-bit ripple-carry adder, with the outputs in each of
+    /// `swaps` exchanged.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> Vec<String> {
+        let mut lines: Vec<_> = (0..bits)
+            .flat_map(|i| [format!("x{i:02}: 0"), format!("y{i:02}: 0")])
+            .collect();
+        lines.push(String::new());
+        let mut gates = vec![
+            ("x00 XOR y00".to_string(), "z00".to_string()),
+            ("x00 AND y00".to_string(), "c00".to_string()),
+        ];
+        for i in 1..bits {
+            let carry = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            gates.extend([
+                (format!("x{i:02} XOR y{i:02}"), format!("a{i:02}")),
+                (format!("x{i:02} AND y{i:02}"), format!("b{i:02}")),
+                (format!("a{i:02} XOR c{:02}", i - 1), format!("z{i:02}")),
+                (format!("a{i:02} AND c{:02}", i - 1), format!("d{i:02}")),
+                (format!("b{i:02} OR d{i:02}"), carry),
+            ]);
+        }
+        for (gate, output) in gates {
+            let output = swaps
+                .iter()
+                .find_map(|&(a, b)| match output.as_str() {
+                    wire if wire == a => Some(b.to_string()),
+                    wire if wire == b => Some(a.to_string()),
+                    _ => None,
+                })
+                .unwrap_or(output);
+            lines.push(format!("{gate} -> {output}"));
+        }
+        lines
+    }
+
+    #[test]
+    fn correct_adder() -> Result<()> {
+        let device = Device::parse(adder(8, &[]).into_iter(), Mode::Strict)?;
+        assert!(device.miswired().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn swapped_adder() -> Result<()> {
+        let swaps = [
+            ("z02", "c02"),
+            ("a03", "b03"),
+            ("z05", "d05"),
+            ("b07", "z07"),
+        ];
+        assert_eq!(
+            swapped_wires(adder(8, &swaps).into_iter(), Mode::Strict)?,
+            "a03,b03,b07,c02,d05,z02,z05,z07"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::{parse::Mode, solution::Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Untimed runs before measuring.
+    pub warmup: usize,
+    /// Timed runs, at least one.
+    pub runs: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "nanos")]
+    pub mean: Duration,
+}
+
+pub(crate) fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Stats {
+    fn of(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    pub answer: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Times the parse and solve phases of `S` on `input` separately; each run
+/// parses afresh since solving consumes the parsed input.
+pub fn measure<S: Solution>(
+    input: &str,
+    mode: Mode,
+    config: Config,
+) -> Result<Benchmark, S::Error> {
+    for _ in 0..config.warmup {
+        S::run(input, mode)?;
+    }
+    let runs = config.runs.max(1);
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    let mut answer = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = S::parse(input, mode)?;
+        let parsed_at = Instant::now();
+        let solved = S::solve(parsed)?;
+        solve.push(parsed_at.elapsed());
+        parse.push(parsed_at - start);
+        answer = Some(solved);
+    }
+    Ok(Benchmark {
+        day: S::DAY,
+        part: S::PART,
+        title: S::TITLE,
+        answer: answer.map(|answer| answer.to_string()).unwrap_or_default(),
+        runs,
+        parse: Stats::of(parse),
+        solve: Stats::of(solve),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::of(vec![ms(4), ms(1), ms(10), ms(3)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(3500));
+        assert_eq!(stats.mean, Duration::from_micros(4500));
+        let stats = Stats::of(vec![ms(4), ms(1), ms(10)]);
+        assert_eq!(stats.median, ms(4));
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    fmt::Display,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::{CommandFactory, FromArgMatches, Parser, ValueEnum};
+use serde::Serialize;
+
+use crate::{
+    bench,
+    input::{self, Source},
+    parse::{self, Diagnostic, Mode},
+    solution::Solution,
+};
+
+/// Options shared by the day binaries and the `aoc` runner.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct Options {
+    /// Puzzle input file, or `-` for stdin [default: inputs/DD.txt]
+    pub input: Option<Source>,
+    /// Skip malformed input lines instead of reporting them
+    #[arg(long)]
+    pub lenient: bool,
+    /// How to print the result
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Answer: <answer>`
+    #[default]
+    Text,
+    /// The bare answer
+    Plain,
+    /// Day, part, answer, elapsed time and diagnostics as a JSON object
+    Json,
+}
+
+impl Options {
+    pub fn mode(&self) -> Mode {
+        if self.lenient {
+            Mode::Lenient
+        } else {
+            Mode::Strict
+        }
+    }
+
+    pub fn read_input(&self, day: u8) -> input::Result<String> {
+        input::load(self.input.as_ref(), day)
+    }
+}
+
+/// The result of running one solution, as reported to the user.
+#[derive(Debug, Serialize)]
+pub struct Output {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    #[serde(rename = "elapsed_ns", serialize_with = "bench::nanos")]
+    pub elapsed: Duration,
+    pub error: Option<String>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Output {
+    /// Times `solve`, capturing its answer or its error along with any parse
+    /// diagnostics found in the error's source chain.
+    pub fn capture<A, E>(day: u8, part: u8, solve: impl FnOnce() -> Result<A, E>) -> Self
+    where
+        A: Display,
+        E: std::error::Error + 'static,
+    {
+        let start = Instant::now();
+        let result = solve();
+        let elapsed = start.elapsed();
+        match result {
+            Ok(answer) => Self {
+                day,
+                part,
+                answer: Some(answer.to_string()),
+                elapsed,
+                error: None,
+                diagnostics: Vec::new(),
+            },
+            Err(e) => Self {
+                elapsed,
+                ..Self::failed(day, part, &e)
+            },
+        }
+    }
+
+    pub fn failed(day: u8, part: u8, error: &(dyn std::error::Error + 'static)) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            error: Some(error.to_string()),
+            diagnostics: diagnostics(error),
+        }
+    }
+
+    pub fn print(&self, format: Format) -> ExitCode {
+        match (format, &self.answer, &self.error) {
+            (Format::Json, _, _) => match serde_json::to_string(self) {
+                Ok(json) => println!("{json}"),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            (Format::Text, Some(answer), _) => println!("Answer: {answer}"),
+            (Format::Plain, Some(answer), _) => println!("{answer}"),
+            (_, None, error) => eprintln!("Error: {}", error.as_deref().unwrap_or_default()),
+        }
+        if self.error.is_some() {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn diagnostics(error: &(dyn std::error::Error + 'static)) -> Vec<Diagnostic> {
+    let mut next = Some(error);
+    while let Some(error) = next {
+        if let Some(parse::Error::Diagnostics(diagnostics)) = error.downcast_ref() {
+            return diagnostics.clone();
+        }
+        next = error.source();
+    }
+    Vec::new()
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    options: Options,
+}
+
+/// Entry point for a single day binary: solves its input and prints the
+/// result.
+pub fn run<S: Solution>() -> ExitCode {
+    let about = format!("Day {:02} part {}: {}", S::DAY, S::PART, S::TITLE);
+    let matches = Cli::command().about(about).get_matches();
+    let Cli { options } = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let output = match options.read_input(S::DAY) {
+        Ok(input) => Output::capture(S::DAY, S::PART, || S::run(&input, options.mode())),
+        Err(e) => Output::failed(S::DAY, S::PART, &e),
+    };
+    output.print(options.format)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    enum DayError {
+        #[error("{0}")]
+        Parse(#[from] parse::Error),
+    }
+
+    #[test]
+    fn captures_answer() {
+        let output = Output::capture(1, 2, || Ok::<_, DayError>(42));
+        assert_eq!(output.answer.as_deref(), Some("42"));
+        assert!(output.error.is_none());
+    }
+
+    #[test]
+    fn captures_diagnostics() {
+        let output = Output::capture(1, 1, || {
+            parse::lines(["1", "x"], Mode::Strict, |line| {
+                nom::character::complete::u64(line)
+            })
+            .map(|numbers| numbers.len())
+            .map_err(DayError::from)
+        });
+        assert_eq!(output.answer, None);
+        assert_eq!(output.diagnostics.len(), 1);
+        assert_eq!(output.diagnostics[0].line, 2);
+        let json = serde_json::to_value(&output).unwrap_or_default();
+        assert_eq!(json["diagnostics"][0]["expected"], "digit");
+        assert_eq!(json["day"], 1);
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    geom::{Direction, Direction8, Point},
+    parse,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn from_lines<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+        mut cell: impl FnMut(char) -> T,
+    ) -> parse::Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in lines {
+            let line = line.as_ref();
+            if line.is_empty() {
+                continue;
+            }
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let row_width = cells.len() - before;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(parse::Error::Parse(format!(
+                    "grid row {} has width {row_width}, expected {width}",
+                    height + 1
+                )));
+            }
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    fn index_of(&self, point: &Point) -> Option<usize> {
+        let (x, y) = point.as_indices()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::from_indices(index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.point_of(index), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.rows().nth(y)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.ray(Point::from_indices(x, 0), Direction::S.into())
+            .map(|(_, cell)| cell)
+    }
+
+    /// Cells from `start` (inclusive) stepping by `step` until leaving the grid,
+    /// e.g. a step of `(1, 1)` walks a diagonal.
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let mut next = Some(start);
+        std::iter::from_fn(move || {
+            let point = next?;
+            let cell = self.get(&point)?;
+            next = (step != Point::ORIGIN).then_some(point + step);
+            Some((point, cell))
+        })
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.positions_of(value).next()
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter_map(move |(point, cell)| (cell == value).then_some(point))
+    }
+
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| point + direction)
+            .filter(|neighbour| self.contains(neighbour))
+    }
+
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| point + direction)
+            .filter(|neighbour| self.contains(neighbour))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = parse::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_lines(s.lines(), |ch| ch)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        abc
+        def
+    "};
+
+    #[test]
+    fn bounds_checked_get() -> parse::Result<()> {
+        let grid: Grid<char> = EXAMPLE.parse()?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(-1, 0)), None);
+        Ok(())
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn rows_columns_and_rays() -> parse::Result<()> {
+        let grid: Grid<char> = EXAMPLE.parse()?;
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        let diagonal: String = grid
+            .ray(Point::new(0, 0), Point::new(1, 1))
+            .map(|(_, ch)| ch)
+            .collect();
+        assert_eq!(diagonal, "ae");
+        Ok(())
+    }
+
+    #[test]
+    fn find_and_neighbours() -> parse::Result<()> {
+        let grid: Grid<char> = EXAMPLE.parse()?;
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn display_round_trip() -> parse::Result<()> {
+        let grid: Grid<char> = EXAMPLE.parse()?;
+        assert_eq!(grid.to_string(), EXAMPLE);
+        Ok(())
+    }
+}
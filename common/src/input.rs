@@ -0,0 +1,126 @@
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read {from}: {error}")]
+    Read { from: Source, error: io::Error },
+}
+
+impl Error {
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::Read { error, .. } => error.kind() == io::ErrorKind::NotFound,
+        }
+    }
+}
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// The conventional location of a day's input, `inputs/DD.txt`.
+    pub fn default_for(day: u8) -> Self {
+        Self::in_dir("inputs", day)
+    }
+
+    /// A day's input in `dir`, named `DD.txt`.
+    pub fn in_dir(dir: impl AsRef<Path>, day: u8) -> Self {
+        Self::File(dir.as_ref().join(format!("{day:02}.txt")))
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let raw = match self {
+            Self::Stdin => io::read_to_string(io::stdin()),
+            Self::File(path) => fs::read_to_string(path),
+        }
+        .map_err(|error| Error::Read {
+            from: self.clone(),
+            error,
+        })?;
+        Ok(normalise(&raw))
+    }
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Self::Stdin,
+            path => Self::File(path.into()),
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "stdin"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Reads `source`, falling back to the default location for `day`.
+pub fn load(source: Option<&Source>, day: u8) -> Result<String> {
+    match source {
+        Some(source) => source.read(),
+        None => Source::default_for(day).read(),
+    }
+}
+
+/// Converts CRLF line endings to LF and ends non-empty input with exactly one
+/// newline.
+pub fn normalise(raw: &str) -> String {
+    let mut text = raw.replace("\r\n", "\n");
+    text.truncate(text.trim_end_matches('\n').len());
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalises_line_endings() {
+        assert_eq!(normalise("1 2\r\n3 4\r\n\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalise("1 2\n3 4"), "1 2\n3 4\n");
+        assert_eq!(normalise("\n\n"), "");
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!(
+            "day.txt".parse(),
+            Ok(Source::File(PathBuf::from("day.txt")))
+        );
+        assert_eq!(Source::default_for(7).to_string(), "inputs/07.txt");
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = Source::File("does/not/exist.txt".into())
+            .read()
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("failed to read does/not/exist.txt: "));
+    }
+}
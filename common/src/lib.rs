@@ -1 +1,7 @@
+pub mod bench;
+pub mod cli;
+pub mod geom;
+pub mod grid;
+pub mod input;
 pub mod parse;
+pub mod solution;
@@ -1,17 +1,33 @@
-use nom::IResult;
+use std::fmt::Display;
+
+use nom::error::{VerboseError, VerboseErrorKind};
+use serde::Serialize;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+pub type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("{0}")]
     Parse(String),
+    #[error("{}", render(.0))]
+    Diagnostics(Vec<Diagnostic>),
 }
 
-impl From<nom::Err<nom::error::Error<&str>>> for Error {
-    fn from(value: nom::Err<nom::error::Error<&str>>) -> Self {
-        Self::Parse(value.to_string())
+fn render(diagnostics: &[Diagnostic]) -> String {
+    match diagnostics {
+        [diagnostic] => diagnostic.to_string(),
+        _ => format!(
+            "{} parse errors:\n{}",
+            diagnostics.len(),
+            diagnostics
+                .iter()
+                .map(Diagnostic::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
     }
 }
 
@@ -19,5 +35,239 @@ pub trait Parse
 where
     Self: Sized,
 {
-    fn parse(input: &str) -> IResult<&str, Self>;
+    fn parse(input: &str) -> IResult<'_, Self>;
+}
+
+/// How line-oriented inputs treat lines that fail to parse.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Collect every malformed line into [`Error::Diagnostics`].
+    #[default]
+    Strict,
+    /// Silently skip malformed lines.
+    Lenient,
+}
+
+/// Where and why parsing failed, with the offending source line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub source: String,
+}
+
+impl Diagnostic {
+    /// A diagnostic for parsing that stopped at `rest`, a suffix of `input`.
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = input.len() - rest.len();
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            source: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    fn from_nom(input: &str, error: nom::Err<VerboseError<&str>>) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let rest = e.errors.first().map_or(input, |(rest, _)| rest);
+                let expected = e
+                    .errors
+                    .iter()
+                    .find_map(|(_, kind)| match kind {
+                        VerboseErrorKind::Context(context) => Some(context.to_string()),
+                        _ => None,
+                    })
+                    .or_else(|| {
+                        e.errors.first().map(|(_, kind)| match kind {
+                            VerboseErrorKind::Char(ch) => format!("`{ch}`"),
+                            VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+                            VerboseErrorKind::Context(context) => context.to_string(),
+                        })
+                    })
+                    .unwrap_or_else(|| "valid input".to_string());
+                Self::at(input, rest, expected)
+            }
+            nom::Err::Incomplete(_) => Self::at(input, "", "more input"),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+fn apply<T>(
+    input: &str,
+    mode: Mode,
+    end: &str,
+    mut parser: impl FnMut(&str) -> IResult<'_, T>,
+) -> std::result::Result<T, Diagnostic> {
+    match parser(input) {
+        Ok((rest, _)) if mode == Mode::Strict && !rest.trim_end().is_empty() => {
+            Err(Diagnostic::at(input, rest, end))
+        }
+        Ok((_, parsed)) => Ok(parsed),
+        Err(e) => Err(Diagnostic::from_nom(input, e)),
+    }
+}
+
+/// Parses the whole of `input`, which in strict mode must be consumed
+/// entirely, bar trailing whitespace.
+pub fn complete<T>(
+    input: &str,
+    mode: Mode,
+    parser: impl FnMut(&str) -> IResult<'_, T>,
+) -> Result<T> {
+    apply(input, mode, "end of input", parser).map_err(|e| Error::Diagnostics(vec![e]))
+}
+
+/// Like [`complete`] for a single line, numbered `number` in the diagnostic.
+pub fn line<T>(
+    number: usize,
+    text: &str,
+    mode: Mode,
+    parser: impl FnMut(&str) -> IResult<'_, T>,
+) -> std::result::Result<T, Diagnostic> {
+    apply(text, mode, "end of line", parser).map_err(|e| Diagnostic { line: number, ..e })
+}
+
+/// Parses each non-blank line of `lines`, numbering them from 1.
+pub fn lines<S, T>(
+    lines: impl IntoIterator<Item = S>,
+    mode: Mode,
+    parser: impl FnMut(&str) -> IResult<'_, T>,
+) -> Result<Vec<T>>
+where
+    S: AsRef<str>,
+{
+    numbered_lines(
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(idx, text)| (idx + 1, text)),
+        mode,
+        parser,
+    )
+}
+
+/// Like [`lines`], for callers that have already numbered (a section of) the
+/// input.
+pub fn numbered_lines<S, T>(
+    lines: impl IntoIterator<Item = (usize, S)>,
+    mode: Mode,
+    mut parser: impl FnMut(&str) -> IResult<'_, T>,
+) -> Result<Vec<T>>
+where
+    S: AsRef<str>,
+{
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+    for (number, text) in lines {
+        let text = text.as_ref();
+        if text.trim().is_empty() {
+            continue;
+        }
+        match line(number, text, mode, &mut parser) {
+            Ok(item) => parsed.push(item),
+            Err(e) if mode == Mode::Strict => errors.push(e),
+            Err(_) => {}
+        }
+    }
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(Error::Diagnostics(errors))
+    }
+}
+
+/// Combines the results of parsing two sections of one input, keeping the
+/// diagnostics of both.
+pub fn both<A, B>(a: Result<A>, b: Result<B>) -> Result<(A, B)> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok((a, b)),
+        (Err(Error::Diagnostics(mut a)), Err(Error::Diagnostics(b))) => {
+            a.extend(b);
+            Err(Error::Diagnostics(a))
+        }
+        (Err(e), _) | (_, Err(e)) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use nom::{
+        character::complete::{char, u64},
+        error::context,
+        sequence::separated_pair,
+    };
+
+    fn pair(input: &str) -> IResult<'_, (u64, u64)> {
+        separated_pair(u64, context("`,` between numbers", char(',')), u64)(input)
+    }
+
+    const EXAMPLE: &str = indoc! {"
+        1,2
+        3;4
+
+        5,6,7
+        8,9
+    "};
+
+    #[test]
+    fn strict_reports_every_malformed_line() {
+        let Err(Error::Diagnostics(errors)) = lines(EXAMPLE.lines(), Mode::Strict, pair) else {
+            panic!("expected diagnostics");
+        };
+        let positions: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(positions, [(2, 2), (4, 4)]);
+        assert_eq!(errors[0].expected, "`,` between numbers");
+        assert_eq!(errors[1].expected, "end of line");
+    }
+
+    #[test]
+    fn lenient_skips_malformed_lines() -> Result<()> {
+        assert_eq!(
+            lines(EXAMPLE.lines(), Mode::Lenient, pair)?,
+            [(1, 2), (5, 6), (8, 9)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn caret_snippet() {
+        let Err(error) = complete("1,2\n3;4\n", Mode::Strict, |input| {
+            separated_pair(pair, char('\n'), pair)(input)
+        }) else {
+            panic!("expected diagnostics");
+        };
+        assert_eq!(
+            error.to_string(),
+            indoc! {"
+                line 2, column 2: expected `,` between numbers
+                  |
+                2 | 3;4
+                  |  ^"}
+        );
+    }
 }
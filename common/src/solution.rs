@@ -0,0 +1,21 @@
+use std::fmt::Display;
+
+use crate::parse::Mode;
+
+pub trait Solution {
+    const DAY: u8;
+    const PART: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Answer: Display;
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    fn parse(input: &str, mode: Mode) -> Result<Self::Input, Self::Error>;
+
+    fn solve(input: Self::Input) -> Result<Self::Answer, Self::Error>;
+
+    fn run(input: &str, mode: Mode) -> Result<Self::Answer, Self::Error> {
+        Self::solve(Self::parse(input, mode)?)
+    }
+}
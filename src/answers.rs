@@ -0,0 +1,90 @@
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::{Error, Result};
+
+/// Expected answers for real puzzle inputs, kept in a TOML file such as
+///
+/// ```toml
+/// [day01]
+/// part1 = 2264607
+/// part2 = "19457120"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u8, u8), String>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        fs::read_to_string(path)
+            .map_err(|e| Error::Answers(format!("failed to read {}: {e}", path.display())))?
+            .parse()
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+fn key_number(key: &str, prefix: &str) -> Result<u8> {
+    key.strip_prefix(prefix)
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| Error::Answers(format!("expected a key like `{prefix}1`, found `{key}`")))
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let days: HashMap<String, HashMap<String, Answer>> =
+            toml::from_str(s).map_err(|e| Error::Answers(e.to_string()))?;
+        let mut answers = HashMap::new();
+        for (day, parts) in days {
+            let day = key_number(&day, "day")?;
+            for (part, answer) in parts {
+                let answer = match answer {
+                    Answer::Number(n) => n.to_string(),
+                    Answer::Text(text) => text,
+                };
+                answers.insert((day, key_number(&part, "part")?), answer);
+            }
+        }
+        Ok(Self(answers))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn numbers_and_strings() -> Result<()> {
+        let answers: Answers = indoc! {r#"
+            [day01]
+            part1 = 11
+            part2 = "31"
+
+            [day12]
+            part1 = 1930
+        "#}
+        .parse()?;
+        assert_eq!(answers.get(1, 1), Some("11"));
+        assert_eq!(answers.get(1, 2), Some("31"));
+        assert_eq!(answers.get(12, 1), Some("1930"));
+        assert_eq!(answers.get(12, 2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_keys() {
+        assert!("[one]\npart1 = 1".parse::<Answers>().is_err());
+        assert!("[day01]\nfirst = 1".parse::<Answers>().is_err());
+    }
+}
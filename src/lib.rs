@@ -1,14 +1,150 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
+use common::{
+    bench::{self, Benchmark},
+    parse::Mode,
+    solution::Solution,
+};
+use thiserror::Error;
+
+pub mod answers;
+pub mod verify;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("no solution for day {day:02} part {part} (see `aoc list`)")]
+    Unimplemented { day: u8, part: u8 },
+    #[error(transparent)]
+    Input(#[from] common::input::Error),
+    #[error("invalid answers file: {0}")]
+    Answers(String),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Solution(Box<dyn std::error::Error + Send + Sync>),
+}
+
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    run: fn(&str, Mode) -> Result<String>,
+    bench: fn(&str, Mode, bench::Config) -> Result<Benchmark>,
+}
+
+impl Entry {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            part: S::PART,
+            title: S::TITLE,
+            run: run::<S>,
+            bench: bench::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, mode: Mode) -> Result<String> {
+        (self.run)(input, mode)
+    }
+
+    pub fn bench(&self, input: &str, mode: Mode, config: bench::Config) -> Result<Benchmark> {
+        (self.bench)(input, mode, config)
+    }
+}
+
+fn run<S: Solution>(input: &str, mode: Mode) -> Result<String> {
+    S::run(input, mode)
+        .map(|answer| answer.to_string())
+        .map_err(|e| Error::Solution(Box::new(e)))
 }
 
+fn bench<S: Solution>(input: &str, mode: Mode, config: bench::Config) -> Result<Benchmark> {
+    bench::measure::<S>(input, mode, config).map_err(|e| Error::Solution(Box::new(e)))
+}
+
+pub fn find(day: u8, part: u8) -> Result<&'static Entry> {
+    SOLUTIONS
+        .iter()
+        .find(|entry| entry.day == day && entry.part == part)
+        .ok_or(Error::Unimplemented { day, part })
+}
+
+pub static SOLUTIONS: &[Entry] = &[
+    Entry::of::<aoc2024_01_1::Solver>(),
+    Entry::of::<aoc2024_01_2::Solver>(),
+    Entry::of::<aoc2024_02_1::Solver>(),
+    Entry::of::<aoc2024_02_2::Solver>(),
+    Entry::of::<aoc2024_03_1::Solver>(),
+    Entry::of::<aoc2024_03_2::Solver>(),
+    Entry::of::<aoc2024_04_1::Solver>(),
+    Entry::of::<aoc2024_04_2::Solver>(),
+    Entry::of::<aoc2024_05_1::Solver>(),
+    Entry::of::<aoc2024_05_2::Solver>(),
+    Entry::of::<aoc2024_06_1::Solver>(),
+    Entry::of::<aoc2024_06_2::Solver>(),
+    Entry::of::<aoc2024_07_1::Solver>(),
+    Entry::of::<aoc2024_07_2::Solver>(),
+    Entry::of::<aoc2024_08_1::Solver>(),
+    Entry::of::<aoc2024_08_2::Solver>(),
+    Entry::of::<aoc2024_09_1::Solver>(),
+    Entry::of::<aoc2024_09_2::Solver>(),
+    Entry::of::<aoc2024_10_1::Solver>(),
+    Entry::of::<aoc2024_10_2::Solver>(),
+    Entry::of::<aoc2024_11_1::Solver>(),
+    Entry::of::<aoc2024_11_2::Solver>(),
+    Entry::of::<aoc2024_12_1::Solver>(),
+    Entry::of::<aoc2024_12_2::Solver>(),
+    Entry::of::<aoc2024_13_1::Solver>(),
+    Entry::of::<aoc2024_13_2::Solver>(),
+    Entry::of::<aoc2024_14_1::Solver>(),
+    Entry::of::<aoc2024_14_2::Solver>(),
+    Entry::of::<aoc2024_15_1::Solver>(),
+    Entry::of::<aoc2024_15_2::Solver>(),
+    Entry::of::<aoc2024_16_1::Solver>(),
+    Entry::of::<aoc2024_16_2::Solver>(),
+    Entry::of::<aoc2024_17_1::Solver>(),
+    Entry::of::<aoc2024_17_2::Solver>(),
+    Entry::of::<aoc2024_18_1::Solver>(),
+    Entry::of::<aoc2024_18_2::Solver>(),
+    Entry::of::<aoc2024_19_1::Solver>(),
+    Entry::of::<aoc2024_19_2::Solver>(),
+    Entry::of::<aoc2024_20_1::Solver>(),
+    Entry::of::<aoc2024_20_2::Solver>(),
+    Entry::of::<aoc2024_21_1::Solver>(),
+    Entry::of::<aoc2024_21_2::Solver>(),
+    Entry::of::<aoc2024_22_1::Solver>(),
+    Entry::of::<aoc2024_22_2::Solver>(),
+    Entry::of::<aoc2024_23_1::Solver>(),
+    Entry::of::<aoc2024_23_2::Solver>(),
+    Entry::of::<aoc2024_24_1::Solver>(),
+    Entry::of::<aoc2024_24_2::Solver>(),
+];
+
 #[cfg(test)]
-mod tests {
+mod test {
     use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn dispatch() -> Result<()> {
+        let example = indoc! {"
+            3   4
+            4   3
+            2   5
+            1   3
+            3   9
+            3   3
+        "};
+        assert_eq!(find(1, 1)?.run(example, Mode::Strict)?, "11");
+        assert_eq!(find(1, 2)?.run(example, Mode::Strict)?, "31");
+        Ok(())
+    }
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn unimplemented() {
+        assert!(matches!(
+            find(25, 2),
+            Err(Error::Unimplemented { day: 25, part: 2 })
+        ));
     }
 }
@@ -0,0 +1,203 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc2024::{
+    answers::Answers,
+    find,
+    verify::{verify, Summary},
+    Result, SOLUTIONS,
+};
+use clap::{Parser, Subcommand};
+use common::{
+    bench::{self, Benchmark},
+    cli::{Options, Output},
+    input::Source,
+    parse::Mode,
+};
+
+#[derive(Parser)]
+#[command(
+    about = "Advent of Code 2024 solutions",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Puzzle day
+    #[arg(short, long, required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Puzzle part
+    #[arg(short, long, required = true, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    #[command(flatten)]
+    options: Options,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List available solutions
+    List,
+    /// Check every solution against its real input and expected answer.
+    ///
+    /// Exits with 0 if all pass, 1 if any fail, or 2 if none fail but some
+    /// inputs or answers are missing.
+    Verify {
+        /// TOML file of expected answers, with `partN` keys under `[dayNN]`
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Directory holding the `DD.txt` puzzle inputs
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+        /// Skip malformed input lines instead of reporting them
+        #[arg(long)]
+        lenient: bool,
+    },
+    /// Time the parse and solve phases of solutions on their real inputs.
+    ///
+    /// Solutions that fail are reported and skipped, and the exit code is 1.
+    Bench {
+        /// Only benchmark this day
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Only benchmark this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Directory holding the `DD.txt` puzzle inputs
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = bench::Config::default().warmup)]
+        warmup: usize,
+        /// Timed runs
+        #[arg(long, default_value_t = bench::Config::default().runs)]
+        runs: usize,
+        /// Skip malformed input lines instead of reporting them
+        #[arg(long)]
+        lenient: bool,
+        /// Emit results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    tracing_subscriber::fmt::init();
+    run(Cli::parse()).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        ExitCode::FAILURE
+    })
+}
+
+fn run(cli: Cli) -> Result<ExitCode> {
+    match (cli.command, cli.day, cli.part) {
+        (Some(Command::List), _, _) => {
+            for entry in SOLUTIONS {
+                println!("day {:02} part {}: {}", entry.day, entry.part, entry.title);
+            }
+        }
+        (
+            Some(Command::Verify {
+                answers,
+                inputs,
+                lenient,
+            }),
+            _,
+            _,
+        ) => {
+            let answers = Answers::load(&answers)?;
+            let mode = if lenient { Mode::Lenient } else { Mode::Strict };
+            let mut summary = Summary::default();
+            for entry in SOLUTIONS {
+                let outcome = verify(entry, &answers, &inputs, mode);
+                println!("day {:02} part {}: {outcome}", entry.day, entry.part);
+                summary.record(&outcome);
+            }
+            println!("{summary}");
+            return Ok(summary.exit_code());
+        }
+        (
+            Some(Command::Bench {
+                day,
+                part,
+                inputs,
+                warmup,
+                runs,
+                lenient,
+                json,
+            }),
+            _,
+            _,
+        ) => {
+            let config = bench::Config { warmup, runs };
+            let mode = if lenient { Mode::Lenient } else { Mode::Strict };
+            let mut benchmarks = Vec::new();
+            let mut failed = false;
+            for entry in SOLUTIONS
+                .iter()
+                .filter(|entry| day.is_none_or(|day| entry.day == day))
+                .filter(|entry| part.is_none_or(|part| entry.part == part))
+            {
+                let source = Source::in_dir(&inputs, entry.day);
+                let input = match source.read() {
+                    Ok(input) => input,
+                    Err(e) if e.is_not_found() => {
+                        eprintln!(
+                            "day {:02} part {}: skipped, missing input {source}",
+                            entry.day, entry.part
+                        );
+                        continue;
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+                let benchmark = match entry.bench(&input, mode, config) {
+                    Ok(benchmark) => benchmark,
+                    Err(e) => {
+                        eprintln!("day {:02} part {}: error: {e}", entry.day, entry.part);
+                        failed = true;
+                        continue;
+                    }
+                };
+                if !json {
+                    print_benchmark(&benchmark);
+                }
+                benchmarks.push(benchmark);
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&benchmarks)?);
+            }
+            if failed {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        (None, Some(day), Some(part)) => {
+            let options = &cli.options;
+            let solved = find(day, part).and_then(|solution| {
+                let input = options.read_input(day)?;
+                Ok(Output::capture(day, part, || {
+                    solution.run(&input, options.mode())
+                }))
+            });
+            let output = solved.unwrap_or_else(|e| Output::failed(day, part, &e));
+            return Ok(output.print(options.format));
+        }
+        _ => unreachable!("clap requires --day and --part without a subcommand"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_benchmark(benchmark: &Benchmark) {
+    let stats = |stats: &bench::Stats| {
+        format!(
+            "{:.1?} / {:.1?} / {:.1?}",
+            stats.min, stats.median, stats.mean
+        )
+    };
+    println!(
+        "day {:02} part {}: parse {}, solve {} (min / median / mean of {} runs)",
+        benchmark.day,
+        benchmark.part,
+        stats(&benchmark.parse),
+        stats(&benchmark.solve),
+        benchmark.runs
+    );
+}
@@ -0,0 +1,146 @@
+use std::{fmt::Display, path::Path, process::ExitCode};
+
+use common::{input::Source, parse::Mode};
+
+use crate::{answers::Answers, Entry, Error};
+
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(Error),
+    MissingInput(Source),
+    MissingAnswer { actual: String },
+}
+
+impl Outcome {
+    fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail { .. } | Self::Error(_))
+    }
+
+    fn is_missing(&self) -> bool {
+        matches!(self, Self::MissingInput(_) | Self::MissingAnswer { .. })
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected, actual } => {
+                write!(f, "FAIL: expected {expected}, got {actual}")
+            }
+            Self::Error(e) => write!(f, "FAIL: {e}"),
+            Self::MissingInput(source) => write!(f, "missing input {source}"),
+            Self::MissingAnswer { actual } => write!(f, "missing answer (got {actual})"),
+        }
+    }
+}
+
+/// Runs `entry` on its input in `inputs` and checks the result against
+/// `answers`.
+pub fn verify(entry: &Entry, answers: &Answers, inputs: &Path, mode: Mode) -> Outcome {
+    let source = Source::in_dir(inputs, entry.day);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) if e.is_not_found() => return Outcome::MissingInput(source),
+        Err(e) => return Outcome::Error(e.into()),
+    };
+    let actual = match entry.run(&input, mode) {
+        Ok(actual) => actual,
+        Err(e) => return Outcome::Error(e),
+    };
+    match answers.get(entry.day, entry.part) {
+        None => Outcome::MissingAnswer { actual },
+        Some(expected) if expected == actual => Outcome::Pass,
+        Some(expected) => Outcome::Fail {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+#[derive(Default)]
+pub struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl Summary {
+    pub fn record(&mut self, outcome: &Outcome) {
+        if outcome.is_failure() {
+            self.failed += 1;
+        } else if outcome.is_missing() {
+            self.missing += 1;
+        } else {
+            self.passed += 1;
+        }
+    }
+
+    /// `0` if everything passed, `1` on any failure, `2` if nothing failed but
+    /// some inputs or answers are missing.
+    pub fn exit_code(&self) -> ExitCode {
+        match (self.failed, self.missing) {
+            (0, 0) => ExitCode::SUCCESS,
+            (0, _) => ExitCode::from(2),
+            _ => ExitCode::FAILURE,
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing",
+            self.passed, self.failed, self.missing
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::find;
+    use indoc::indoc;
+    use std::fs;
+
+    #[test]
+    fn outcomes() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let inputs = std::env::temp_dir().join(format!("aoc2024-verify-{}", std::process::id()));
+        fs::create_dir_all(&inputs)?;
+        fs::write(
+            inputs.join("01.txt"),
+            indoc! {"
+                3   4
+                4   3
+                2   5
+                1   3
+                3   9
+                3   3
+            "},
+        )?;
+        let answers: Answers = "[day01]\npart1 = 11\n[day02]\npart1 = 2".parse()?;
+        let outcome = |day, part| -> crate::Result<Outcome> {
+            Ok(verify(find(day, part)?, &answers, &inputs, Mode::Strict))
+        };
+        assert!(matches!(outcome(1, 1)?, Outcome::Pass));
+        assert!(matches!(
+            outcome(1, 2)?,
+            Outcome::MissingAnswer { actual } if actual == "31"
+        ));
+        assert!(matches!(outcome(2, 1)?, Outcome::MissingInput(_)));
+
+        let answers: Answers = "[day01]\npart1 = 12".parse()?;
+        let outcome = verify(find(1, 1)?, &answers, &inputs, Mode::Strict);
+        assert!(
+            matches!(&outcome, Outcome::Fail { expected, actual } if expected == "12" && actual == "11")
+        );
+        let mut summary = Summary::default();
+        summary.record(&outcome);
+        assert_eq!(summary.exit_code(), ExitCode::FAILURE);
+
+        fs::remove_dir_all(&inputs)?;
+        Ok(())
+    }
+}